@@ -4,20 +4,32 @@ use std::{
     ops::Range,
 };
 
+use crate::tokens::Radix;
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Position {
     pub idx: Range<usize>,
     pub ln: Range<usize>,
     pub col: Range<usize>,
+    /// The terminal cells covered, accounting for tab stops, wide characters and
+    /// grapheme clusters.
+    pub vcol: Range<usize>,
 }
 impl Position {
+    /// Creates a position that assumes every character takes up exactly one cell.
     pub fn new(idx: Range<usize>, ln: Range<usize>, col: Range<usize>) -> Self {
-        Self { idx, ln, col }
+        Self {
+            idx,
+            ln,
+            vcol: col.clone(),
+            col,
+        }
     }
     pub fn extend(&mut self, other: &Self) {
         self.idx.end = other.idx.end;
         self.ln.end = other.ln.end;
         self.col.end = other.col.end;
+        self.vcol.end = other.vcol.end;
     }
 }
 pub struct Located<T> {
@@ -65,6 +77,29 @@ pub enum ErrorType {
     BadChar(char),
     UnclosedString,
     InvalidSymbol(String),
+    InvalidUtf8,
+    Io(String),
+    BidiControl(char),
+    MixedScript(String),
+    Confusable(String, String),
+    MissingDigits(Radix),
+    InvalidDigit(char, Radix),
+    MisplacedSeparator(char),
+    MalformedExponent,
+    IntOverflow,
+    UnknownSuffix(String),
+    MalformedEscape(String),
+    InvalidCodePoint(u32),
+    UnclosedRawString(String),
+    UnclosedInterpolation(String),
+    NonAsciiByte(char),
+    InvalidByteEscape(String),
+    UnterminatedHeredoc(String),
+    EmptyChar,
+    UnterminatedChar,
+    TooManyChars,
+    UnknownEscape(char),
+    HeredocInLiteral,
 }
 #[derive(Debug, Clone, PartialEq)]
 pub struct Error {
@@ -94,6 +129,124 @@ impl Display for Error {
                 "invalid symbol '{}' at {}, (ln: {}, col: {})",
                 s, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
             ),
+            ErrorType::InvalidUtf8 => write!(
+                f,
+                "invalid utf-8 at {}, (ln: {}, col: {})",
+                self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::Io(err) => write!(
+                f,
+                "io error '{}' at {}, (ln: {}, col: {})",
+                err, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::BidiControl(c) => write!(
+                f,
+                "bidirectional control character {:?} at {}, (ln: {}, col: {})",
+                c, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::MixedScript(ident) => write!(
+                f,
+                "identifier '{}' mixes scripts at {}, (ln: {}, col: {})",
+                ident, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::Confusable(ident, skeleton) => write!(
+                f,
+                "identifier '{}' is confusable with '{}' at {}, (ln: {}, col: {})",
+                ident, skeleton, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::MissingDigits(radix) => write!(
+                f,
+                "missing digits after '{}' at {}, (ln: {}, col: {})",
+                radix.prefix(),
+                self.pos.idx.start,
+                self.pos.ln.start,
+                self.pos.col.start
+            ),
+            ErrorType::InvalidDigit(c, radix) => write!(
+                f,
+                "invalid {} digit '{}' at {}, (ln: {}, col: {})",
+                radix, c, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::MisplacedSeparator(c) => write!(
+                f,
+                "digit separator '{}' not between digits at {}, (ln: {}, col: {})",
+                c, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::MalformedExponent => write!(
+                f,
+                "missing exponent digits at {}, (ln: {}, col: {})",
+                self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::IntOverflow => write!(
+                f,
+                "integer literal out of range at {}, (ln: {}, col: {})",
+                self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::UnknownSuffix(suffix) => write!(
+                f,
+                "unknown numeric suffix '{}' at {}, (ln: {}, col: {})",
+                suffix, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::MalformedEscape(escape) => write!(
+                f,
+                "malformed escape '{}' at {}, (ln: {}, col: {})",
+                escape, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::InvalidCodePoint(code) => write!(
+                f,
+                "invalid code point U+{:X} at {}, (ln: {}, col: {})",
+                code, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::UnclosedRawString(closing) => write!(
+                f,
+                "unclosed raw string, expected '{}' at {}, (ln: {}, col: {})",
+                closing, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::UnclosedInterpolation(closer) => write!(
+                f,
+                "unclosed interpolation, expected '{}' at {}, (ln: {}, col: {})",
+                closer, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::NonAsciiByte(c) => write!(
+                f,
+                "non-ascii character '{}' in byte literal at {}, (ln: {}, col: {})",
+                c, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::InvalidByteEscape(escape) => write!(
+                f,
+                "invalid escape '{}' in byte literal at {}, (ln: {}, col: {})",
+                escape, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::UnterminatedHeredoc(terminator) => write!(
+                f,
+                "unterminated heredoc, expected '{}' at {}, (ln: {}, col: {})",
+                terminator, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::EmptyChar => write!(
+                f,
+                "empty character literal at {}, (ln: {}, col: {})",
+                self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::UnterminatedChar => write!(
+                f,
+                "unterminated character literal at {}, (ln: {}, col: {})",
+                self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::TooManyChars => write!(
+                f,
+                "character literal with more than one character at {}, (ln: {}, col: {})",
+                self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::UnknownEscape(c) => write!(
+                f,
+                "unknown escape '\\{}' at {}, (ln: {}, col: {})",
+                c, self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
+            ErrorType::HeredocInLiteral => write!(
+                f,
+                "literal runs into the body of a heredoc at {}, (ln: {}, col: {})",
+                self.pos.idx.start, self.pos.ln.start, self.pos.col.start
+            ),
         }
     }
 }
@@ -109,6 +262,29 @@ impl error::Error for Error {
             ErrorType::BadChar(_) => "bad character",
             ErrorType::UnclosedString => "unclosed string",
             ErrorType::InvalidSymbol(_) => "invalid symbol",
+            ErrorType::InvalidUtf8 => "invalid utf-8",
+            ErrorType::Io(_) => "io error",
+            ErrorType::BidiControl(_) => "bidirectional control character",
+            ErrorType::MixedScript(_) => "mixed script identifier",
+            ErrorType::Confusable(_, _) => "confusable identifier",
+            ErrorType::MissingDigits(_) => "missing digits",
+            ErrorType::InvalidDigit(_, _) => "invalid digit",
+            ErrorType::MisplacedSeparator(_) => "misplaced digit separator",
+            ErrorType::MalformedExponent => "malformed exponent",
+            ErrorType::IntOverflow => "integer overflow",
+            ErrorType::UnknownSuffix(_) => "unknown numeric suffix",
+            ErrorType::MalformedEscape(_) => "malformed escape",
+            ErrorType::InvalidCodePoint(_) => "invalid code point",
+            ErrorType::UnclosedRawString(_) => "unclosed raw string",
+            ErrorType::UnclosedInterpolation(_) => "unclosed interpolation",
+            ErrorType::NonAsciiByte(_) => "non-ascii byte",
+            ErrorType::InvalidByteEscape(_) => "invalid byte escape",
+            ErrorType::UnterminatedHeredoc(_) => "unterminated heredoc",
+            ErrorType::EmptyChar => "empty character literal",
+            ErrorType::UnterminatedChar => "unterminated character literal",
+            ErrorType::TooManyChars => "too many characters in character literal",
+            ErrorType::UnknownEscape(_) => "unknown escape",
+            ErrorType::HeredocInLiteral => "literal in heredoc",
         }
     }
 }
@@ -1,227 +1,217 @@
-use crate::error::{Error, ErrorType, Located, Position};
+use crate::error::{Error, Located};
+use crate::lexer::{self, DecimalPoint, IntWidth, Policy, Quote, Shebang};
 use crate::tokens::Token;
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct Line {
+pub struct Line<'src> {
     pub ln: usize,
-    pub tokens: Vec<Located<Token>>,
+    pub tokens: Vec<Located<Token<'src>>>,
     pub indent: usize,
 }
-impl Line {
+impl<'src> Line<'src> {
     pub fn len(&self) -> usize {
         self.tokens.len()
     }
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    pub fn pop(&mut self) -> Option<Located<Token>> {
+    pub fn pop(&mut self) -> Option<Located<Token<'src>>> {
         self.tokens.pop()
     }
-    pub fn remove(&mut self, idx: usize) -> Located<Token> {
+    pub fn remove(&mut self, idx: usize) -> Located<Token<'src>> {
         self.tokens.remove(idx)
     }
 }
 
-pub struct Lexer {
-    pub lines: Vec<String>,
-    pub symbols: Vec<String>,
-    pub keywords: Vec<String>,
-    pub idx: usize,
-    pub ln: usize,
-    pub col: usize,
+pub struct Lexer<'src> {
+    pub lexer: lexer::Lexer<'src>,
 }
-impl Lexer {
-    pub fn new(text: String) -> Self {
+impl<'src> Lexer<'src> {
+    /// Strings end at the end of their line unless `multiline_strings` is turned on.
+    pub fn new(text: &'src str) -> Self {
         Self {
-            lines: text.split('\n').map(|s| s.to_string()).collect(),
-            symbols: vec![],
-            keywords: vec![],
-            idx: 0,
-            ln: 0,
-            col: 0,
+            lexer: lexer::Lexer::new(text).multiline_strings(false),
         }
     }
-    pub fn pos(&self) -> Position {
-        Position {
-            idx: self.idx..self.idx + 1,
-            ln: self.ln..self.ln + 1,
-            col: self.col..self.col + 1,
-        }
+    pub fn symbols(mut self, symbols: &[&str]) -> Self {
+        self.lexer = self.lexer.symbols(symbols);
+        self
     }
-    pub fn advance(&mut self) {
-        self.idx += 1;
-        self.col += 1;
+    pub fn keywords(mut self, keywords: &[&str]) -> Self {
+        self.lexer = self.lexer.keywords(keywords);
+        self
     }
-    pub fn advance_line(&mut self) {
-        self.idx += 1;
-        self.ln += 1;
-        self.col = 0;
+    pub fn unicode_idents(mut self, unicode_idents: bool) -> Self {
+        self.lexer = self.lexer.unicode_idents(unicode_idents);
+        self
     }
-    pub fn get(&self) -> Option<char> {
-        self.lines.get(self.ln).and_then(|line| {
-            line.get(self.col..self.col + 1)
-                .and_then(|s| s.chars().next())
-        })
+    pub fn ident_start(mut self, f: impl Fn(char) -> bool + 'static) -> Self {
+        self.lexer = self.lexer.ident_start(f);
+        self
     }
-    pub fn next_char(&mut self) -> Option<char> {
-        let c = self.get();
-        self.advance();
-        c
+    pub fn ident_continue(mut self, f: impl Fn(char) -> bool + 'static) -> Self {
+        self.lexer = self.lexer.ident_continue(f);
+        self
     }
-    pub fn has_symbols(&self) -> bool {
-        !self.symbols.is_empty()
+    pub fn ident_start_chars(mut self, chars: &str) -> Self {
+        self.lexer = self.lexer.ident_start_chars(chars);
+        self
     }
-    pub fn symbols(mut self, symbols: &[&str]) -> Self {
-        self.symbols = symbols.iter().map(|symbol| symbol.to_string()).collect();
+    pub fn ident_continue_chars(mut self, chars: &str) -> Self {
+        self.lexer = self.lexer.ident_continue_chars(chars);
         self
     }
-    pub fn keywords(mut self, keywords: &[&str]) -> Self {
-        self.keywords = keywords.iter().map(|symbol| symbol.to_string()).collect();
+    pub fn skip_bom(mut self, skip_bom: bool) -> Self {
+        self.lexer = self.lexer.skip_bom(skip_bom);
+        self
+    }
+    pub fn shebang(mut self, shebang: Shebang) -> Self {
+        self.lexer = self.lexer.shebang(shebang);
+        self
+    }
+    pub fn normalize_newlines(mut self, normalize_newlines: bool) -> Self {
+        self.lexer = self.lexer.normalize_newlines(normalize_newlines);
+        self
+    }
+    pub fn bidi_controls(mut self, policy: Policy) -> Self {
+        self.lexer = self.lexer.bidi_controls(policy);
+        self
+    }
+    pub fn confusables(mut self, policy: Policy) -> Self {
+        self.lexer = self.lexer.confusables(policy);
+        self
+    }
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.lexer = self.lexer.tab_width(tab_width);
+        self
+    }
+    pub fn radix_prefixes(mut self, radix_prefixes: bool) -> Self {
+        self.lexer = self.lexer.radix_prefixes(radix_prefixes);
+        self
+    }
+    pub fn digit_separator(mut self, separator: char) -> Self {
+        self.lexer = self.lexer.digit_separator(separator);
+        self
+    }
+    pub fn exponents(mut self, exponents: bool) -> Self {
+        self.lexer = self.lexer.exponents(exponents);
+        self
+    }
+    pub fn int_width(mut self, int_width: IntWidth) -> Self {
+        self.lexer = self.lexer.int_width(int_width);
+        self
+    }
+    pub fn suffixes(mut self, suffixes: &[&str]) -> Self {
+        self.lexer = self.lexer.suffixes(suffixes);
+        self
+    }
+    pub fn strict_suffixes(mut self, strict_suffixes: bool) -> Self {
+        self.lexer = self.lexer.strict_suffixes(strict_suffixes);
+        self
+    }
+    pub fn decimal_point(mut self, decimal_point: DecimalPoint) -> Self {
+        self.lexer = self.lexer.decimal_point(decimal_point);
+        self
+    }
+    pub fn leading_dot_floats(mut self, leading_dot_floats: bool) -> Self {
+        self.lexer = self.lexer.leading_dot_floats(leading_dot_floats);
         self
     }
-    pub fn lex(&mut self) -> Result<Vec<Line>, Error> {
+    pub fn signed_numbers(mut self, signed_numbers: bool) -> Self {
+        self.lexer = self.lexer.signed_numbers(signed_numbers);
+        self
+    }
+    pub fn special_floats(mut self, special_floats: &[(&str, f64)]) -> Self {
+        self.lexer = self.lexer.special_floats(special_floats);
+        self
+    }
+    pub fn null_escapes(mut self, null_escapes: bool) -> Self {
+        self.lexer = self.lexer.null_escapes(null_escapes);
+        self
+    }
+    pub fn hex_escapes(mut self, hex_escapes: bool) -> Self {
+        self.lexer = self.lexer.hex_escapes(hex_escapes);
+        self
+    }
+    pub fn unicode_escapes(mut self, unicode_escapes: bool) -> Self {
+        self.lexer = self.lexer.unicode_escapes(unicode_escapes);
+        self
+    }
+    pub fn short_unicode_escapes(mut self, short_unicode_escapes: bool) -> Self {
+        self.lexer = self.lexer.short_unicode_escapes(short_unicode_escapes);
+        self
+    }
+    pub fn raw_strings(mut self, raw_strings: bool) -> Self {
+        self.lexer = self.lexer.raw_strings(raw_strings);
+        self
+    }
+    pub fn multiline_strings(mut self, multiline_strings: bool) -> Self {
+        self.lexer = self.lexer.multiline_strings(multiline_strings);
+        self
+    }
+    pub fn triple_quoted_strings(mut self, triple_quoted_strings: bool) -> Self {
+        self.lexer = self.lexer.triple_quoted_strings(triple_quoted_strings);
+        self
+    }
+    pub fn dedent(mut self, dedent: bool) -> Self {
+        self.lexer = self.lexer.dedent(dedent);
+        self
+    }
+    pub fn interpolation(mut self, opener: &str, closer: &str) -> Self {
+        self.lexer = self.lexer.interpolation(opener, closer);
+        self
+    }
+    pub fn quote(mut self, quote: char, kind: Quote) -> Self {
+        self.lexer = self.lexer.quote(quote, kind);
+        self
+    }
+    pub fn byte_literals(mut self, byte_literals: bool) -> Self {
+        self.lexer = self.lexer.byte_literals(byte_literals);
+        self
+    }
+    pub fn lifetimes(mut self, lifetimes: bool) -> Self {
+        self.lexer = self.lexer.lifetimes(lifetimes);
+        self
+    }
+    pub fn escapes(mut self, escapes: &[(char, char)]) -> Self {
+        self.lexer = self.lexer.escapes(escapes);
+        self
+    }
+    pub fn escape_decoder(mut self, f: impl Fn(&str) -> Option<(char, usize)> + 'static) -> Self {
+        self.lexer = self.lexer.escape_decoder(f);
+        self
+    }
+    pub fn unknown_escapes(mut self, policy: Policy) -> Self {
+        self.lexer = self.lexer.unknown_escapes(policy);
+        self
+    }
+    pub fn heredocs(mut self, introducer: &str) -> Self {
+        self.lexer = self.lexer.heredocs(introducer);
+        self
+    }
+    pub fn lex(&mut self) -> Result<Vec<Line<'src>>, Error> {
         let mut lines = vec![];
-        while self.ln < self.lines.len() {
+        let mut prelude = self.lexer.lex_prelude()?;
+        loop {
+            let ln = self.lexer.ln;
+            self.lexer.after_operand = false;
             let mut indent = 0;
-            while let Some(' ' | '\t') = self.get() {
-                self.advance();
+            while let Some(' ' | '\t') = self.lexer.get() {
+                self.lexer.advance();
                 indent += 1;
             }
-            let mut tokens = vec![];
-            while let Some(c) = self.get() {
-                let mut pos = self.pos();
+            let mut tokens: Vec<_> = prelude.take().into_iter().collect();
+            while let Some(c) = self.lexer.get() {
                 match c {
-                    ' ' | '\t' | '\r' => {
-                        self.advance();
-                    }
-                    '0'..='9' => {
-                        let mut num = self.next_char().unwrap().to_string();
-                        while let Some('0'..='9') = self.get() {
-                            pos.extend(&self.pos());
-                            num.push(self.next_char().unwrap());
-                        }
-                        if let Some('.') = self.get() {
-                            pos.extend(&self.pos());
-                            num.push(self.next_char().unwrap());
-                            while let Some('0'..='9') = self.get() {
-                                num.push(self.next_char().unwrap());
-                                pos.extend(&self.pos());
-                            }
-                            tokens.push(Located::new(Token::Float(num.parse().unwrap()), pos));
-                        } else {
-                            tokens.push(Located::new(Token::Int(num.parse().unwrap()), pos));
-                        }
-                    }
-                    'a'..='z' | 'A'..='Z' | '_' => {
-                        let mut ident = self.next_char().unwrap().to_string();
-                        while let Some('a'..='z') | Some('A'..='Z') | Some('_') | Some('0'..='9') =
-                            self.get()
-                        {
-                            pos.extend(&self.pos());
-                            ident.push(self.next_char().unwrap());
-                        }
-                        tokens.push(Located::new(
-                            if self.keywords.contains(&ident) {
-                                Token::Keyword(ident)
-                            } else {
-                                Token::Ident(ident)
-                            },
-                            pos,
-                        ));
-                    }
-                    '\'' => {
-                        self.advance();
-                        let mut c = self.next_char().unwrap();
-                        if c == '\\' {
-                            c = match self.next_char().unwrap() {
-                                'n' => '\n',
-                                't' => '\t',
-                                'r' => '\r',
-                                '\\' => '\\',
-                                '\'' => '\'',
-                                _ => return Err(Error::new(ErrorType::BadChar(c), pos)),
-                            }
-                        }
-                        pos.extend(&self.pos());
-                        if self.next_char().unwrap() != '\'' {
-                            return Err(Error::new(ErrorType::BadChar(c), pos));
-                        }
-                        tokens.push(Located::new(Token::Char(c), pos));
-                    }
-                    '"' => {
-                        self.advance();
-                        let mut string = String::new();
-                        while let Some(c) = self.get() {
-                            if c == '"' {
-                                break;
-                            }
-                            let c = self.next_char().unwrap();
-                            if c == '\\' {
-                                let c = match self.next_char().unwrap() {
-                                    'n' => '\n',
-                                    't' => '\t',
-                                    'r' => '\r',
-                                    '\\' => '\\',
-                                    '"' => '"',
-                                    _ => return Err(Error::new(ErrorType::BadChar(c), pos)),
-                                };
-                                string.push(c);
-                            } else {
-                                string.push(c);
-                            }
-                        }
-                        if let Some('"') = self.get() {
-                            pos.extend(&self.pos());
-                            self.advance();
-                            tokens.push(Located::new(Token::String(string), pos));
-                        } else {
-                            return Err(Error::new(ErrorType::UnclosedString, pos));
-                        }
-                    }
-                    _ => {
-                        if self.has_symbols() {
-                            let mut symbol = self.next_char().unwrap().to_string();
-                            while self.get().is_some() {
-                                let matches = self
-                                    .symbols
-                                    .iter()
-                                    .filter(|s| *s == &symbol || s.starts_with(&symbol))
-                                    .count();
-                                if matches == 0 {
-                                    symbol.pop();
-                                    break;
-                                } else if matches == 1 {
-                                    break;
-                                } else {
-                                    symbol.push(self.next_char().unwrap());
-                                    pos.extend(&self.pos());
-                                }
-                            }
-                            if self.symbols.iter().any(|s| s == &symbol) {
-                                if symbol.len() == 1 {
-                                    tokens.push(Located::new(
-                                        Token::Symbol(symbol.chars().next().unwrap()),
-                                        pos,
-                                    ));
-                                } else {
-                                    tokens.push(Located::new(Token::LongSymbol(symbol), pos));
-                                }
-                            } else {
-                                return Err(Error::new(ErrorType::InvalidSymbol(symbol), pos));
-                            }
-                        } else {
-                            let c = self.next_char().unwrap();
-                            tokens.push(Located::new(Token::Symbol(c), pos));
-                        }
-                    }
+                    '\n' | '\r' => break,
+                    ' ' | '\t' => self.lexer.advance(),
+                    _ => tokens.extend(self.lexer.next_token()?),
                 }
             }
-            lines.push(Line {
-                ln: self.ln,
-                tokens,
-                indent,
-            });
-            self.advance_line();
+            lines.push(Line { ln, tokens, indent });
+            if !self.lexer.end_line() {
+                break;
+            }
         }
         Ok(lines)
     }
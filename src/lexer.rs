@@ -1,45 +1,262 @@
+use std::{borrow::Cow, ops::Range, rc::Rc};
+
 use crate::error::{Error, ErrorType, Located, Position};
-use crate::tokens::Token;
+use crate::tokens::{Radix, Segment, Token};
+use crate::unicode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Shebang {
+    /// Lex a leading `#!` line like any other text.
+    #[default]
+    Lex,
+    /// Skip a leading `#!` line as trivia.
+    Skip,
+    /// Surface a leading `#!` line as a [`Token::Shebang`].
+    Token,
+}
+
+/// What to do about suspicious but lexable input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Policy {
+    #[default]
+    Allow,
+    /// Record an [`Error`] in [`Lexer::warnings`] and keep lexing.
+    Warn,
+    /// Fail with an [`Error`].
+    Deny,
+}
+
+/// Which integer literals are lexed beyond the range of [`Token::Int`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntWidth {
+    /// Literals above `i64::MAX` are an [`ErrorType::IntOverflow`].
+    #[default]
+    I64,
+    /// Literals up to `u64::MAX` become a [`Token::WideInt`].
+    U64,
+    /// Literals up to `i128::MAX` become a [`Token::WideInt`].
+    I128,
+    /// Literals above `i64::MAX` become a [`Token::BigInt`] of any size.
+    Big,
+}
+
+/// When a `.` after the digits of a number is its decimal point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalPoint {
+    /// Always, so `1.` is a float.
+    #[default]
+    Always,
+    /// Only if a digit follows, so `1..10` and `1.abs()` start with an integer.
+    BeforeDigit,
+}
+
+/// What the text between two quote characters is lexed as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quote {
+    String,
+    Char,
+    /// A [`Token::Ident`] that may contain any character but the quote, like SQL's `"my column"`.
+    Ident,
+    /// Not a quote, so the character is lexed like any other.
+    Disabled,
+}
 
-pub struct Lexer {
-    pub text: String,
+/// Decodes the text after a `\` into a character and the length of the escape in bytes.
+pub type EscapeDecoder = Rc<dyn Fn(&str) -> Option<(char, usize)>>;
+
+/// Everything that configures a lexer, independent of the text being lexed.
+#[derive(Clone)]
+pub struct Options {
     pub symbols: Vec<String>,
     pub keywords: Vec<String>,
+    pub unicode_idents: bool,
+    pub ident_start: Option<Rc<dyn Fn(char) -> bool>>,
+    pub ident_continue: Option<Rc<dyn Fn(char) -> bool>>,
+    pub ident_start_chars: Vec<char>,
+    pub ident_continue_chars: Vec<char>,
+    pub skip_bom: bool,
+    pub shebang: Shebang,
+    pub normalize_newlines: bool,
+    pub bidi_controls: Policy,
+    pub confusables: Policy,
+    pub tab_width: usize,
+    pub radix_prefixes: bool,
+    pub digit_separator: Option<char>,
+    pub exponents: bool,
+    pub int_width: IntWidth,
+    pub suffixes: Vec<String>,
+    pub strict_suffixes: bool,
+    pub decimal_point: DecimalPoint,
+    pub leading_dot_floats: bool,
+    pub signed_numbers: bool,
+    pub special_floats: Vec<(String, f64)>,
+    pub null_escapes: bool,
+    pub hex_escapes: bool,
+    pub unicode_escapes: bool,
+    pub short_unicode_escapes: bool,
+    pub raw_strings: bool,
+    pub multiline_strings: bool,
+    pub triple_quoted_strings: bool,
+    pub dedent: bool,
+    pub interpolation: Option<(String, String)>,
+    pub quotes: Vec<(char, Quote)>,
+    pub byte_literals: bool,
+    pub heredoc: Option<String>,
+    pub lifetimes: bool,
+    pub escapes: Vec<(char, char)>,
+    pub escape_decoder: Option<EscapeDecoder>,
+    pub unknown_escapes: Policy,
+}
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            symbols: vec![],
+            keywords: vec![],
+            unicode_idents: false,
+            ident_start: None,
+            ident_continue: None,
+            ident_start_chars: vec![],
+            ident_continue_chars: vec![],
+            skip_bom: false,
+            shebang: Shebang::Lex,
+            normalize_newlines: false,
+            bidi_controls: Policy::Allow,
+            confusables: Policy::Allow,
+            tab_width: 4,
+            radix_prefixes: false,
+            digit_separator: None,
+            exponents: true,
+            int_width: IntWidth::I64,
+            suffixes: vec![],
+            strict_suffixes: false,
+            decimal_point: DecimalPoint::Always,
+            leading_dot_floats: false,
+            signed_numbers: false,
+            special_floats: vec![],
+            null_escapes: false,
+            hex_escapes: false,
+            unicode_escapes: false,
+            short_unicode_escapes: false,
+            raw_strings: false,
+            multiline_strings: true,
+            triple_quoted_strings: false,
+            dedent: false,
+            interpolation: None,
+            quotes: vec![('"', Quote::String), ('\'', Quote::Char)],
+            byte_literals: false,
+            heredoc: None,
+            lifetimes: false,
+            escapes: vec![('n', '\n'), ('t', '\t'), ('r', '\r'), ('\\', '\\')],
+            escape_decoder: None,
+            unknown_escapes: Policy::Deny,
+        }
+    }
+}
+
+pub struct Lexer<'src> {
+    pub text: &'src str,
+    pub options: Options,
     pub idx: usize,
     pub ln: usize,
     pub col: usize,
+    pub vcol: usize,
+    pub warnings: Vec<Error>,
+    /// Whether the last token can end an operand, making a following sign an operator.
+    pub after_operand: bool,
+    /// Whether the last character is a regional indicator starting a flag, which the next one
+    /// completes.
+    pub open_flag: bool,
+    denied: Option<Error>,
+    /// The bodies of the heredocs introduced on the current line, skipped at its end.
+    heredoc_bodies: Option<Range<usize>>,
 }
-impl Lexer {
-    pub fn new(text: String) -> Self {
+impl<'src> Lexer<'src> {
+    pub fn new(text: &'src str) -> Self {
+        Self::with_options(text, Options::default())
+    }
+    pub fn with_options(text: &'src str, options: Options) -> Self {
         Self {
             text,
-            symbols: vec![],
-            keywords: vec![],
+            options,
             idx: 0,
             ln: 0,
             col: 0,
+            vcol: 0,
+            warnings: vec![],
+            after_operand: false,
+            open_flag: false,
+            denied: None,
+            heredoc_bodies: None,
         }
     }
     pub fn pos(&self) -> Position {
+        let len = self.get().map_or(1, char::len_utf8);
         Position {
-            idx: self.idx..self.idx + 1,
+            idx: self.idx..self.idx + len,
             ln: self.ln..self.ln + 1,
             col: self.col..self.col + 1,
+            vcol: self.vcol..self.vcol + self.cells(),
+        }
+    }
+    /// Returns how many terminal cells the current character takes up at the current
+    /// visual column, which is 0 if it continues the grapheme cluster before it.
+    pub fn cells(&self) -> usize {
+        let Some(c) = self.get() else {
+            return 1;
+        };
+        if c == '\t' {
+            let tab_width = self.options.tab_width.max(1);
+            return tab_width - self.vcol % tab_width;
+        }
+        let before = self.text[..self.idx].chars().rev();
+        if unicode::is_pictographic(c) && before.clone().next() == Some('\u{200d}') {
+            return 0;
         }
+        if unicode::is_regional_indicator(c) {
+            // a pair of regional indicators renders as one two cell wide flag
+            return if self.open_flag { 0 } else { 2 };
+        }
+        unicode::width(c)
     }
     pub fn advance(&mut self) {
-        self.idx += 1;
-        if self.text.get(self.idx..self.idx + 1) == Some("\n") {
-            self.ln += 1;
-            self.col = 0;
-        } else {
-            self.col += 1;
+        if let Some(c) = self.get() {
+            if unicode::is_bidi_control(c) && self.options.bidi_controls != Policy::Allow {
+                let error = Error::new(ErrorType::BidiControl(c), self.pos());
+                if let Err(error) = self.report(self.options.bidi_controls, error) {
+                    self.denied.get_or_insert(error);
+                }
+            }
+            let cells = self.cells();
+            self.open_flag = unicode::is_regional_indicator(c) && !self.open_flag;
+            self.idx += c.len_utf8();
+            let line_break = match c {
+                '\n' => true,
+                // a carriage return only breaks the line on its own, not as part of `\r\n`
+                '\r' => self.get() != Some('\n'),
+                _ => false,
+            };
+            if line_break {
+                self.ln += 1;
+                self.col = 0;
+                self.vcol = 0;
+            } else if c != '\r' {
+                self.col += 1;
+                self.vcol += cells;
+            }
+        }
+    }
+    pub fn report(&mut self, policy: Policy, error: Error) -> Result<(), Error> {
+        match policy {
+            Policy::Allow => Ok(()),
+            Policy::Warn => {
+                self.warnings.push(error);
+                Ok(())
+            }
+            Policy::Deny => Err(error),
         }
     }
     pub fn get(&self) -> Option<char> {
-        self.text
-            .get(self.idx..self.idx + 1)
-            .and_then(|s| s.chars().next())
+        self.text.get(self.idx..).and_then(|s| s.chars().next())
     }
     pub fn next_char(&mut self) -> Option<char> {
         let c = self.get();
@@ -47,146 +264,1282 @@ impl Lexer {
         c
     }
     pub fn has_symbols(&self) -> bool {
-        !self.symbols.is_empty()
+        !self.options.symbols.is_empty()
     }
     pub fn symbols(mut self, symbols: &[&str]) -> Self {
-        self.symbols = symbols.iter().map(|symbol| symbol.to_string()).collect();
+        self.options.symbols = symbols.iter().map(|symbol| symbol.to_string()).collect();
         self
     }
     pub fn keywords(mut self, keywords: &[&str]) -> Self {
-        self.keywords = keywords.iter().map(|symbol| symbol.to_string()).collect();
+        self.options.keywords = keywords.iter().map(|symbol| symbol.to_string()).collect();
         self
     }
-    pub fn lex(&mut self) -> Result<Vec<Located<Token>>, Error> {
-        let mut tokens = vec![];
+    pub fn unicode_idents(mut self, unicode_idents: bool) -> Self {
+        self.options.unicode_idents = unicode_idents;
+        self
+    }
+    /// Replaces the default rule for the first character of an identifier.
+    pub fn ident_start(mut self, f: impl Fn(char) -> bool + 'static) -> Self {
+        self.options.ident_start = Some(Rc::new(f));
+        self
+    }
+    /// Replaces the default rule for the remaining characters of an identifier.
+    pub fn ident_continue(mut self, f: impl Fn(char) -> bool + 'static) -> Self {
+        self.options.ident_continue = Some(Rc::new(f));
+        self
+    }
+    /// Allows `chars` to start an identifier in addition to the current rule.
+    pub fn ident_start_chars(mut self, chars: &str) -> Self {
+        self.options.ident_start_chars = chars.chars().collect();
+        self
+    }
+    /// Allows `chars` to continue an identifier in addition to the current rule.
+    pub fn ident_continue_chars(mut self, chars: &str) -> Self {
+        self.options.ident_continue_chars = chars.chars().collect();
+        self
+    }
+    pub fn skip_bom(mut self, skip_bom: bool) -> Self {
+        self.options.skip_bom = skip_bom;
+        self
+    }
+    pub fn shebang(mut self, shebang: Shebang) -> Self {
+        self.options.shebang = shebang;
+        self
+    }
+    /// Turns `\r\n` and lone `\r` line breaks inside literals into `\n`.
+    pub fn normalize_newlines(mut self, normalize_newlines: bool) -> Self {
+        self.options.normalize_newlines = normalize_newlines;
+        self
+    }
+    pub fn bidi_controls(mut self, policy: Policy) -> Self {
+        self.options.bidi_controls = policy;
+        self
+    }
+    /// Sets the policy for identifiers that mix scripts or only consist of letters
+    /// confusable with Latin ones.
+    pub fn confusables(mut self, policy: Policy) -> Self {
+        self.options.confusables = policy;
+        self
+    }
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.options.tab_width = tab_width.max(1);
+        self
+    }
+    /// Lexes `0x`, `0o` and `0b` prefixed integers.
+    pub fn radix_prefixes(mut self, radix_prefixes: bool) -> Self {
+        self.options.radix_prefixes = radix_prefixes;
+        self
+    }
+    /// Allows `separator` between the digits of numeric literals, like `_` in `1_000`.
+    pub fn digit_separator(mut self, separator: char) -> Self {
+        self.options.digit_separator = Some(separator);
+        self
+    }
+    /// Lexes `e`/`E` exponents like `1e10` or `2.5E-3` as floats, which is on by default.
+    pub fn exponents(mut self, exponents: bool) -> Self {
+        self.options.exponents = exponents;
+        self
+    }
+    pub fn int_width(mut self, int_width: IntWidth) -> Self {
+        self.options.int_width = int_width;
+        self
+    }
+    /// Registers suffixes like `u8` or `ms` that form one [`Token::Suffixed`] with the number
+    /// directly in front of them. Empty suffixes are ignored.
+    pub fn suffixes(mut self, suffixes: &[&str]) -> Self {
+        self.options.suffixes = suffixes
+            .iter()
+            .filter(|suffix| !suffix.is_empty())
+            .map(|suffix| suffix.to_string())
+            .collect();
+        self
+    }
+    /// Makes anything identifier-like directly after a number that is not a registered
+    /// suffix an [`ErrorType::UnknownSuffix`].
+    pub fn strict_suffixes(mut self, strict_suffixes: bool) -> Self {
+        self.options.strict_suffixes = strict_suffixes;
+        self
+    }
+    pub fn decimal_point(mut self, decimal_point: DecimalPoint) -> Self {
+        self.options.decimal_point = decimal_point;
+        self
+    }
+    /// Lexes floats without an integer part like `.5`.
+    pub fn leading_dot_floats(mut self, leading_dot_floats: bool) -> Self {
+        self.options.leading_dot_floats = leading_dot_floats;
+        self
+    }
+    /// Folds a `+` or `-` into the number after it, unless it follows an operand.
+    pub fn signed_numbers(mut self, signed_numbers: bool) -> Self {
+        self.options.signed_numbers = signed_numbers;
+        self
+    }
+    /// Lexes each spelling as a [`Token::Float`] with its value, like `("inf", f64::INFINITY)`.
+    pub fn special_floats(mut self, special_floats: &[(&str, f64)]) -> Self {
+        self.options.special_floats = special_floats
+            .iter()
+            .map(|&(spelling, value)| (spelling.to_string(), value))
+            .collect();
+        self
+    }
+    /// Accepts `\0` in strings and chars.
+    pub fn null_escapes(mut self, null_escapes: bool) -> Self {
+        self.options.null_escapes = null_escapes;
+        self
+    }
+    /// Accepts `\x41` with two hex digits in strings and chars.
+    pub fn hex_escapes(mut self, hex_escapes: bool) -> Self {
+        self.options.hex_escapes = hex_escapes;
+        self
+    }
+    /// Accepts `\u{1F600}` with up to six hex digits in strings and chars.
+    pub fn unicode_escapes(mut self, unicode_escapes: bool) -> Self {
+        self.options.unicode_escapes = unicode_escapes;
+        self
+    }
+    /// Accepts `\u0041` with four hex digits in strings and chars, joining surrogate pairs.
+    pub fn short_unicode_escapes(mut self, short_unicode_escapes: bool) -> Self {
+        self.options.short_unicode_escapes = short_unicode_escapes;
+        self
+    }
+    /// Lexes `r"..."` and `r#"..."#` with any number of `#` as strings without escapes,
+    /// using whichever quotes delimit strings.
+    pub fn raw_strings(mut self, raw_strings: bool) -> Self {
+        self.options.raw_strings = raw_strings;
+        self
+    }
+    /// Lets strings other than triple-quoted ones contain line breaks, which is the default.
+    /// This also covers raw and byte strings and quoted identifiers.
+    pub fn multiline_strings(mut self, multiline_strings: bool) -> Self {
+        self.options.multiline_strings = multiline_strings;
+        self
+    }
+    /// Lexes `"""..."""` strings, which may contain unescaped `"` and line breaks.
+    pub fn triple_quoted_strings(mut self, triple_quoted_strings: bool) -> Self {
+        self.options.triple_quoted_strings = triple_quoted_strings;
+        self
+    }
+    /// Removes the newline after the opening quotes of triple-quoted strings, the indentation
+    /// common to their non-blank lines and all whitespace on blank lines.
+    pub fn dedent(mut self, dedent: bool) -> Self {
+        self.options.dedent = dedent;
+        self
+    }
+    /// Lexes strings containing `opener` as a [`Token::Template`], lexing the text up to the
+    /// matching `closer` as tokens. A backslash before the opener's first character escapes it.
+    /// An empty `opener` or `closer` turns interpolation off.
+    pub fn interpolation(mut self, opener: &str, closer: &str) -> Self {
+        self.options.interpolation = (!opener.is_empty() && !closer.is_empty())
+            .then(|| (opener.to_string(), closer.to_string()));
+        self
+    }
+    /// Sets what `quote` delimits, e.g. `quote('\'', Quote::String)` or
+    /// `quote('`', Quote::Ident)`. By default `"` delimits strings and `'` chars.
+    pub fn quote(mut self, quote: char, kind: Quote) -> Self {
+        self.options.quotes.retain(|&(c, _)| c != quote);
+        if kind != Quote::Disabled {
+            self.options.quotes.push((quote, kind));
+        }
+        self
+    }
+    /// Lexes `b"..."` and `b'.'` as a [`Token::ByteString`] and a [`Token::Byte`],
+    /// using whichever quotes delimit strings and chars.
+    pub fn byte_literals(mut self, byte_literals: bool) -> Self {
+        self.options.byte_literals = byte_literals;
+        self
+    }
+    /// Lexes `<<EOF`, with `<<` as the `introducer`, as a [`Token::String`] holding the lines
+    /// after the current one up to a line that is just `EOF`, which may be indented after
+    /// `<<-EOF`. The rest of the introducing line is lexed as usual and the body skipped at its
+    /// end. The token's position only covers `<<EOF`, not the body.
+    pub fn heredocs(mut self, introducer: &str) -> Self {
+        self.options.heredoc = Some(introducer.to_string());
+        self
+    }
+    /// Lexes a char quote followed by an identifier but no closing quote, like `'a`,
+    /// as a [`Token::Lifetime`].
+    pub fn lifetimes(mut self, lifetimes: bool) -> Self {
+        self.options.lifetimes = lifetimes;
+        self
+    }
+    /// Replaces the escape table, which maps the character after a `\` to the one the escape
+    /// stands for. By default it has `\n`, `\t`, `\r` and `\\`.
+    pub fn escapes(mut self, escapes: &[(char, char)]) -> Self {
+        self.options.escapes = escapes.to_vec();
+        self
+    }
+    /// Decodes escapes in strings and chars before the escape table is consulted: given the
+    /// text after a `\`, returns the character and how many bytes of the text the escape takes,
+    /// which must be at least one.
+    pub fn escape_decoder(mut self, f: impl Fn(&str) -> Option<(char, usize)> + 'static) -> Self {
+        self.options.escape_decoder = Some(Rc::new(f));
+        self
+    }
+    /// What to do about unknown escapes. Unless denied, which is the default, the backslash is
+    /// kept and the character after it lexed like any other.
+    pub fn unknown_escapes(mut self, policy: Policy) -> Self {
+        self.options.unknown_escapes = policy;
+        self
+    }
+    pub fn is_ident_start(&self, c: char) -> bool {
+        if self.options.ident_start_chars.contains(&c) {
+            true
+        } else if let Some(f) = &self.options.ident_start {
+            f(c)
+        } else if self.options.unicode_idents {
+            c == '_' || unicode::is_xid_start(c)
+        } else {
+            c.is_ascii_alphabetic() || c == '_'
+        }
+    }
+    pub fn is_ident_continue(&self, c: char) -> bool {
+        if self.options.ident_continue_chars.contains(&c) {
+            true
+        } else if let Some(f) = &self.options.ident_continue {
+            f(c)
+        } else if self.options.unicode_idents {
+            unicode::is_xid_continue(c)
+        } else {
+            c.is_ascii_alphanumeric() || c == '_'
+        }
+    }
+    /// Returns the longest registered symbol at the current position without consuming it.
+    pub fn match_symbol(&self) -> Option<&'src str> {
+        let rest = self.text.get(self.idx..)?;
+        self.options
+            .symbols
+            .iter()
+            .filter(|symbol| !symbol.is_empty() && rest.starts_with(symbol.as_str()))
+            .map(|symbol| &rest[..symbol.len()])
+            .max_by_key(|symbol| symbol.len())
+    }
+    /// Skips a `\n`, `\r\n` or lone `\r` line break, returning whether there was one.
+    pub fn skip_newline(&mut self) -> bool {
+        match self.get() {
+            Some('\r') => {
+                self.advance();
+                if self.get() == Some('\n') {
+                    self.advance();
+                }
+            }
+            Some('\n') => self.advance(),
+            _ => return false,
+        }
+        true
+    }
+    /// Skips a line break between tokens along with the bodies of the heredocs introduced on
+    /// the line it ends, returning whether there was one.
+    pub fn end_line(&mut self) -> bool {
+        if !self.skip_newline() {
+            return false;
+        }
+        if let Some(bodies) = self.heredoc_bodies.take() {
+            while self.idx < bodies.end {
+                self.advance();
+            }
+        }
+        true
+    }
+    /// Lexes a number whose literal, including a sign, begins at `start`.
+    fn lex_number(&mut self, start: usize, pos: &mut Position) -> Result<Token<'src>, Error> {
+        let number = match self.radix_prefix() {
+            Some(radix) => self.lex_radix_int(start, radix, pos)?,
+            None => self.lex_decimal(start, pos)?,
+        };
+        let suffix = self.suffix();
+        if self.has_suffix() {
+            for _ in suffix.chars() {
+                pos.extend(&self.pos());
+                self.advance();
+            }
+            Ok(Token::Suffixed(Box::new(number), Cow::Borrowed(suffix)))
+        } else if self.options.strict_suffixes && !suffix.is_empty() {
+            let mut suffix_pos = self.pos();
+            for _ in suffix.chars() {
+                suffix_pos.extend(&self.pos());
+                self.advance();
+            }
+            let error_type = ErrorType::UnknownSuffix(suffix.to_string());
+            Err(Error::new(error_type, suffix_pos))
+        } else {
+            Ok(number)
+        }
+    }
+    /// Returns the identifier-like run at the current character, which is a numeric suffix
+    /// if it directly follows a number.
+    fn suffix(&self) -> &'src str {
+        let rest = &self.text[self.idx..];
+        let end = rest
+            .char_indices()
+            .find(|&(_, c)| !self.is_ident_continue(c))
+            .map_or(rest.len(), |(idx, _)| idx);
+        &rest[..end]
+    }
+    fn has_suffix(&self) -> bool {
+        let suffix = self.suffix();
+        !suffix.is_empty() && self.options.suffixes.iter().any(|s| s == suffix)
+    }
+    /// Checks whether the current character is a `.` that belongs to a number
+    /// according to [`Options::decimal_point`].
+    fn is_decimal_point(&self) -> bool {
+        let mut chars = self.text[self.idx..].chars();
+        chars.next() == Some('.')
+            && match self.options.decimal_point {
+                DecimalPoint::Always => true,
+                DecimalPoint::BeforeDigit => chars.next().is_some_and(|c| c.is_ascii_digit()),
+            }
+    }
+    fn lex_decimal(&mut self, start: usize, pos: &mut Position) -> Result<Token<'src>, Error> {
+        let digits = self.idx;
+        self.lex_digits(10, pos)?;
+        let mut float = false;
+        if self.is_decimal_point() {
+            pos.extend(&self.pos());
+            self.advance();
+            self.lex_digits(10, pos)?;
+            float = true;
+        }
+        if self.options.exponents && matches!(self.get(), Some('e' | 'E')) && !self.has_suffix() {
+            pos.extend(&self.pos());
+            self.advance();
+            if let Some('+' | '-') = self.get() {
+                pos.extend(&self.pos());
+                self.advance();
+            }
+            if !self.get().is_some_and(|c| c.is_ascii_digit()) {
+                return Err(Error::new(ErrorType::MalformedExponent, pos.clone()));
+            }
+            self.lex_digits(10, pos)?;
+            float = true;
+        }
+        if float {
+            Ok(Token::Float(self.digits(start).parse().unwrap()))
+        } else {
+            self.int(start, &self.digits(digits), Radix::Decimal, pos)
+        }
+    }
+    /// Turns the digits of an integer literal starting at `start` into a token
+    /// according to [`Options::int_width`], negating it if the literal starts with `-`.
+    fn int(
+        &self,
+        start: usize,
+        digits: &str,
+        radix: Radix,
+        pos: &Position,
+    ) -> Result<Token<'src>, Error> {
+        let negative = self.text[start..].starts_with('-');
+        let value =
+            u128::from_str_radix(digits, radix as u32)
+                .ok()
+                .and_then(|value| match negative {
+                    true => 0i128.checked_sub_unsigned(value),
+                    false => i128::try_from(value).ok(),
+                });
+        if let Some(value) = value.and_then(|value| i64::try_from(value).ok()) {
+            return Ok(Token::Int(value, radix));
+        }
+        let range = match self.options.int_width {
+            IntWidth::I64 => i64::MIN as i128..=i64::MAX as i128,
+            IntWidth::U64 => i64::MIN as i128..=u64::MAX as i128,
+            IntWidth::I128 => i128::MIN..=i128::MAX,
+            IntWidth::Big => return Ok(Token::BigInt(self.digits(start))),
+        };
+        match value {
+            Some(value) if range.contains(&value) => Ok(Token::WideInt(value, radix)),
+            _ => Err(Error::new(ErrorType::IntOverflow, pos.clone())),
+        }
+    }
+    /// Consumes a run of digits in `radix`, including well placed digit separators.
+    fn lex_digits(&mut self, radix: u32, pos: &mut Position) -> Result<(), Error> {
+        let text = self.text;
+        let start = self.idx;
         while let Some(c) = self.get() {
-            let mut pos = self.pos();
-            match c {
-                ' ' | '\t' | '\r' | '\n' => {
+            if Some(c) == self.options.digit_separator {
+                let after = text[self.idx + c.len_utf8()..].chars().next();
+                let before = text[start..self.idx].chars().next_back();
+                let between_digits = before.is_some_and(|c| c.is_digit(radix))
+                    && after.is_some_and(|c| c.is_digit(radix));
+                if !between_digits {
+                    return Err(Error::new(ErrorType::MisplacedSeparator(c), self.pos()));
+                }
+            } else if !c.is_digit(radix) {
+                break;
+            }
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        Ok(())
+    }
+    /// Returns the literal from `start` up to the current character without digit separators.
+    fn digits(&self, start: usize) -> Cow<'src, str> {
+        let literal = &self.text[start..self.idx];
+        match self.options.digit_separator {
+            Some(separator) if literal.contains(separator) => {
+                Cow::Owned(literal.replace(separator, ""))
+            }
+            _ => Cow::Borrowed(literal),
+        }
+    }
+    fn radix_prefix(&self) -> Option<Radix> {
+        if !self.options.radix_prefixes {
+            return None;
+        }
+        let mut chars = self.text[self.idx..].chars();
+        if chars.next() != Some('0') {
+            return None;
+        }
+        match chars.next()? {
+            'x' | 'X' => Some(Radix::Hexadecimal),
+            'o' | 'O' => Some(Radix::Octal),
+            'b' | 'B' => Some(Radix::Binary),
+            _ => None,
+        }
+    }
+    fn lex_radix_int(
+        &mut self,
+        start: usize,
+        radix: Radix,
+        pos: &mut Position,
+    ) -> Result<Token<'src>, Error> {
+        self.advance();
+        pos.extend(&self.pos());
+        self.advance();
+        let digits = self.idx;
+        self.lex_digits(radix as u32, pos)?;
+        if let Some(c) = self.get().filter(char::is_ascii_alphanumeric) {
+            if !self.has_suffix() {
+                return Err(Error::new(ErrorType::InvalidDigit(c, radix), self.pos()));
+            }
+        }
+        if digits == self.idx {
+            return Err(Error::new(ErrorType::MissingDigits(radix), pos.clone()));
+        }
+        self.int(start, &self.digits(digits), radix, pos)
+    }
+    /// Returns the length and value of a special float spelled at `idx`.
+    fn special_float(&self, idx: usize) -> Option<(usize, f64)> {
+        let rest = &self.text[idx..];
+        self.options
+            .special_floats
+            .iter()
+            .find_map(|(spelling, value)| {
+                let after = rest.strip_prefix(spelling.as_str())?;
+                let whole =
+                    !spelling.is_empty() && !after.starts_with(|c| self.is_ident_continue(c));
+                whole.then_some((spelling.len(), *value))
+            })
+    }
+    fn lex_special_float(&mut self, start: usize, pos: &mut Position) -> Option<Token<'src>> {
+        let (len, value) = self.special_float(self.idx)?;
+        let end = self.idx + len;
+        while self.idx < end {
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        let negative = self.text[start..].starts_with('-');
+        Some(Token::Float(if negative { -value } else { value }))
+    }
+    /// Checks whether the current character is a sign to fold into the number after it.
+    fn is_signed_number(&self) -> bool {
+        if !self.options.signed_numbers || self.after_operand {
+            return false;
+        }
+        let rest = &self.text[self.idx + 1..];
+        let digit = |rest: &str| rest.starts_with(|c: char| c.is_ascii_digit());
+        digit(rest)
+            || self.options.leading_dot_floats && rest.starts_with('.') && digit(&rest[1..])
+            || self.special_float(self.idx + 1).is_some()
+    }
+    /// Lexes the escape sequence at the current `\` in a literal closed by `quote`,
+    /// or returns `None` if the text ends right after the backslash.
+    fn lex_escape(&mut self, quote: char) -> Result<Option<char>, Error> {
+        let start = self.idx;
+        let mut pos = self.pos();
+        self.advance();
+        let Some(c) = self.get() else {
+            return Ok(None);
+        };
+        if let Some(decoder) = self.options.escape_decoder.clone() {
+            let rest = &self.text[self.idx..];
+            if let Some((c, len)) = decoder(rest) {
+                if len == 0 || len > rest.len() || !rest.is_char_boundary(len) {
+                    let escape = rest.get(..len).unwrap_or(rest);
+                    let error_type = ErrorType::MalformedEscape(format!("\\{escape}"));
+                    return Err(Error::new(error_type, pos));
+                }
+                let end = self.idx + len;
+                while self.idx < end {
+                    pos.extend(&self.pos());
                     self.advance();
                 }
-                '0'..='9' => {
-                    let mut num = self.next_char().unwrap().to_string();
-                    while let Some('0'..='9') = self.get() {
-                        pos.extend(&self.pos());
-                        num.push(self.next_char().unwrap());
-                    }
-                    if let Some('.') = self.get() {
-                        pos.extend(&self.pos());
-                        num.push(self.next_char().unwrap());
-                        while let Some('0'..='9') = self.get() {
-                            num.push(self.next_char().unwrap());
-                            pos.extend(&self.pos());
-                        }
-                        tokens.push(Located::new(Token::Float(num.parse().unwrap()), pos));
-                    } else {
-                        tokens.push(Located::new(Token::Int(num.parse().unwrap()), pos));
-                    }
+                return Ok(Some(c));
+            }
+        }
+        let replacement = self
+            .options
+            .escapes
+            .iter()
+            .find(|&&(escape, _)| escape == c);
+        if let Some(&(_, replacement)) = replacement {
+            pos.extend(&self.pos());
+            self.advance();
+            return Ok(Some(replacement));
+        }
+        let known = match c {
+            '0' => self.options.null_escapes,
+            'x' => self.options.hex_escapes,
+            'u' => {
+                self.options.short_unicode_escapes
+                    || self.options.unicode_escapes && self.text[self.idx + 1..].starts_with('{')
+            }
+            c => {
+                c == quote
+                    || self
+                        .options
+                        .interpolation
+                        .as_ref()
+                        .is_some_and(|(opener, _)| opener.starts_with(c))
+            }
+        };
+        pos.extend(&self.pos());
+        if !known {
+            // the backslash is kept and the character after it lexed as usual
+            let error = Error::new(ErrorType::UnknownEscape(c), pos);
+            self.report(self.options.unknown_escapes, error)?;
+            return Ok(Some('\\'));
+        }
+        self.advance();
+        let malformed = |lexer: &Self, pos: Position| {
+            let escape = lexer.text[start..lexer.idx].to_string();
+            Err(Error::new(ErrorType::MalformedEscape(escape), pos))
+        };
+        let code = match c {
+            '0' => 0,
+            'x' => match self.hex_digits(2, &mut pos) {
+                (code, 2) => code,
+                _ => return malformed(self, pos),
+            },
+            'u' if self.options.unicode_escapes && self.get() == Some('{') => {
+                pos.extend(&self.pos());
+                self.advance();
+                let (code, len) = self.hex_digits(6, &mut pos);
+                if len == 0 || self.get() != Some('}') {
+                    return malformed(self, pos);
+                }
+                pos.extend(&self.pos());
+                self.advance();
+                code
+            }
+            'u' => {
+                let (mut code, len) = self.hex_digits(4, &mut pos);
+                if len != 4 {
+                    return malformed(self, pos);
                 }
-                'a'..='z' | 'A'..='Z' | '_' => {
-                    let mut ident = self.next_char().unwrap().to_string();
-                    while let Some('a'..='z') | Some('A'..='Z') | Some('_') | Some('0'..='9') =
-                        self.get()
-                    {
+                let low = self.text[self.idx..]
+                    .strip_prefix("\\u")
+                    .and_then(|rest| rest.get(..4))
+                    .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                    .filter(|low| (0xDC00..0xE000).contains(low));
+                if let (0xD800..=0xDBFF, Some(low)) = (code, low) {
+                    for _ in 0..6 {
                         pos.extend(&self.pos());
-                        ident.push(self.next_char().unwrap());
+                        self.advance();
                     }
-                    tokens.push(Located::new(
-                        if self.keywords.contains(&ident) {
-                            Token::Keyword(ident)
-                        } else {
-                            Token::Ident(ident)
-                        },
-                        pos,
-                    ));
-                }
-                '\'' => {
-                    self.advance();
-                    let mut c = self.next_char().unwrap();
-                    if c == '\\' {
-                        c = match self.next_char().unwrap() {
-                            'n' => '\n',
-                            't' => '\t',
-                            'r' => '\r',
-                            '\\' => '\\',
-                            '\'' => '\'',
-                            _ => return Err(Error::new(ErrorType::BadChar(c), pos)),
-                        }
+                    code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                }
+                code
+            }
+            c => c as u32,
+        };
+        match char::from_u32(code) {
+            Some(c) => Ok(Some(c)),
+            None => Err(Error::new(ErrorType::InvalidCodePoint(code), pos)),
+        }
+    }
+    /// Consumes up to `max` hex digits, returning their value and how many there were.
+    fn hex_digits(&mut self, max: usize, pos: &mut Position) -> (u32, usize) {
+        let mut code = 0;
+        let mut len = 0;
+        while len < max {
+            let Some(digit) = self.get().and_then(|c| c.to_digit(16)) else {
+                break;
+            };
+            pos.extend(&self.pos());
+            self.advance();
+            code = code * 16 + digit;
+            len += 1;
+        }
+        (code, len)
+    }
+    /// Returns what the quote character `c` delimits, if anything.
+    pub fn quote_kind(&self, c: char) -> Option<Quote> {
+        let quote = self.options.quotes.iter().find(|&&(quote, _)| quote == c);
+        quote.map(|&(_, kind)| kind)
+    }
+    fn lex_char(&mut self, quote: char, pos: &mut Position) -> Result<Token<'src>, Error> {
+        let text = self.text;
+        self.advance();
+        if let Some(lifetime) = self.lifetime(quote) {
+            while self.idx < lifetime.end {
+                pos.extend(&self.pos());
+                self.advance();
+            }
+            return Ok(Token::Lifetime(Cow::Borrowed(&text[lifetime])));
+        }
+        let c = match self.get() {
+            Some('\\') => match self.lex_escape(quote)? {
+                Some(c) => c,
+                None => return Err(Error::new(ErrorType::UnterminatedChar, pos.clone())),
+            },
+            Some(c) if c != quote => {
+                pos.extend(&self.pos());
+                self.advance();
+                c
+            }
+            _ => return Err(self.char_error(quote, pos)),
+        };
+        if self.get() != Some(quote) {
+            return Err(self.char_error(quote, pos));
+        }
+        pos.extend(&self.pos());
+        self.advance();
+        Ok(Token::Char(c))
+    }
+    /// Returns the span of the identifier after an opening `quote` if it is a lifetime, that is
+    /// if lifetimes are enabled and the identifier is not closed by another `quote`.
+    fn lifetime(&self, quote: char) -> Option<Range<usize>> {
+        if !self.options.lifetimes || !self.get().is_some_and(|c| self.is_ident_start(c)) {
+            return None;
+        }
+        let rest = &self.text[self.idx..];
+        let len = rest
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| !self.is_ident_continue(c))
+            .map_or(rest.len(), |(len, _)| len);
+        let end = self.idx + len;
+        (!rest[len..].starts_with(quote)).then_some(self.idx..end)
+    }
+    /// Consumes the rest of a malformed char literal closed by `quote` on the same line and
+    /// returns why it is malformed.
+    fn char_error(&mut self, quote: char, pos: &mut Position) -> Error {
+        let rest = &self.text[self.idx..];
+        let line = &rest[..rest.find(['\n', '\r']).unwrap_or(rest.len())];
+        let Some(len) = line.find(quote) else {
+            return Error::new(ErrorType::UnterminatedChar, pos.clone());
+        };
+        let end = self.idx + len + quote.len_utf8();
+        while self.idx < end {
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        match len {
+            0 => Error::new(ErrorType::EmptyChar, pos.clone()),
+            _ => Error::new(ErrorType::TooManyChars, pos.clone()),
+        }
+    }
+    /// Returns the quote and its kind if a byte literal starts at the current character.
+    fn byte_literal(&self) -> Option<(char, Quote)> {
+        if !self.options.byte_literals {
+            return None;
+        }
+        let quote = self.text[self.idx..].strip_prefix('b')?.chars().next()?;
+        let kind = self.quote_kind(quote)?;
+        matches!(kind, Quote::String | Quote::Char).then_some((quote, kind))
+    }
+    fn lex_byte(&mut self, quote: char, pos: &mut Position) -> Result<Token<'src>, Error> {
+        self.advance();
+        pos.extend(&self.pos());
+        self.advance();
+        let byte = match self.get() {
+            Some('\\') => match self.lex_byte_escape(quote)? {
+                Some(byte) => byte,
+                None => return Err(Error::new(ErrorType::UnterminatedChar, pos.clone())),
+            },
+            Some(c) if !c.is_ascii() => {
+                return Err(Error::new(ErrorType::NonAsciiByte(c), self.pos()));
+            }
+            Some(c) if c != quote => {
+                pos.extend(&self.pos());
+                self.advance();
+                c as u8
+            }
+            _ => return Err(self.char_error(quote, pos)),
+        };
+        if self.get() != Some(quote) {
+            return Err(self.char_error(quote, pos));
+        }
+        pos.extend(&self.pos());
+        self.advance();
+        Ok(Token::Byte(byte))
+    }
+    fn lex_byte_string(&mut self, quote: char, pos: &mut Position) -> Result<Token<'src>, Error> {
+        self.advance();
+        pos.extend(&self.pos());
+        self.advance();
+        let mut bytes = vec![];
+        while let Some(c) = self.get() {
+            if c == quote || self.ends_string(c) {
+                break;
+            }
+            if c == '\\' {
+                match self.lex_byte_escape(quote)? {
+                    Some(byte) => bytes.push(byte),
+                    None => break,
+                }
+            } else if !c.is_ascii() {
+                return Err(Error::new(ErrorType::NonAsciiByte(c), self.pos()));
+            } else if c == '\r' && self.options.normalize_newlines {
+                self.skip_newline();
+                bytes.push(b'\n');
+            } else {
+                self.advance();
+                bytes.push(c as u8);
+            }
+        }
+        if self.get() != Some(quote) {
+            return Err(Error::new(ErrorType::UnclosedString, pos.clone()));
+        }
+        pos.extend(&self.pos());
+        self.advance();
+        Ok(Token::ByteString(bytes))
+    }
+    /// Lexes the escape sequence at the current `\` in a byte literal closed by `quote`,
+    /// or returns `None` if the text ends right after the backslash.
+    fn lex_byte_escape(&mut self, quote: char) -> Result<Option<u8>, Error> {
+        let start = self.idx;
+        let mut pos = self.pos();
+        self.advance();
+        let Some(c) = self.get() else {
+            return Ok(None);
+        };
+        let replacement = self
+            .options
+            .escapes
+            .iter()
+            .find(|&&(escape, _)| escape == c);
+        let replacement = replacement
+            .map(|&(_, replacement)| replacement)
+            .filter(char::is_ascii);
+        let known = replacement.is_some() || matches!(c, '0' | 'x') || c == quote && c.is_ascii();
+        pos.extend(&self.pos());
+        if !known {
+            let escape = self.text[start..self.idx + c.len_utf8()].to_string();
+            let error = Error::new(ErrorType::InvalidByteEscape(escape), pos);
+            self.report(self.options.unknown_escapes, error)?;
+            return Ok(Some(b'\\'));
+        }
+        self.advance();
+        if let Some(replacement) = replacement {
+            return Ok(Some(replacement as u8));
+        }
+        let byte = match c {
+            '0' => b'\0',
+            'x' => match self.hex_digits(2, &mut pos) {
+                (byte, 2) => byte as u8,
+                _ => {
+                    let escape = self.text[start..self.idx].to_string();
+                    return Err(Error::new(ErrorType::MalformedEscape(escape), pos));
+                }
+            },
+            c => c as u8,
+        };
+        Ok(Some(byte))
+    }
+    /// Lexes the text between two `quote`s as an identifier, without escapes.
+    /// Returns whether `c` is a line break that ends a string without `multiline_strings`.
+    fn ends_string(&self, c: char) -> bool {
+        matches!(c, '\r' | '\n') && !self.options.multiline_strings
+    }
+    fn lex_quoted_ident(&mut self, quote: char, pos: &mut Position) -> Result<Token<'src>, Error> {
+        let text = self.text;
+        self.advance();
+        let content = self.idx;
+        while let Some(c) = self.get() {
+            if c == quote || self.ends_string(c) {
+                break;
+            }
+            self.advance();
+        }
+        if self.get() != Some(quote) {
+            return Err(Error::new(ErrorType::UnclosedString, pos.clone()));
+        }
+        let ident = &text[content..self.idx];
+        pos.extend(&self.pos());
+        self.advance();
+        Ok(Token::Ident(Cow::Borrowed(ident)))
+    }
+    /// Lexes a string delimited by one or, if `triple`, three `quote`s on each side.
+    fn lex_string(
+        &mut self,
+        quote: char,
+        triple: bool,
+        pos: &mut Position,
+    ) -> Result<Token<'src>, Error> {
+        let text = self.text;
+        let count = if triple { 3 } else { 1 };
+        let delimiter = &text[self.idx..self.idx + quote.len_utf8() * count];
+        self.advance();
+        for _ in 1..count {
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        let interpolation = self
+            .options
+            .interpolation
+            .clone()
+            .filter(|(opener, closer)| !opener.is_empty() && !closer.is_empty());
+        let mut segments = vec![];
+        let mut content = self.idx;
+        let mut string: Option<String> = None;
+        while let Some(c) = self.get() {
+            if text[self.idx..].starts_with(delimiter) || !triple && self.ends_string(c) {
+                break;
+            }
+            if let Some((opener, closer)) = interpolation
+                .as_ref()
+                .filter(|(opener, _)| text[self.idx..].starts_with(opener.as_str()))
+            {
+                let literal = match string.take() {
+                    Some(string) => Cow::Owned(string),
+                    None => Cow::Borrowed(&text[content..self.idx]),
+                };
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(literal));
+                }
+                segments.push(Segment::Expr(self.lex_interpolation(opener, closer)?));
+                content = self.idx;
+            } else if c == '\\' {
+                let string = string.get_or_insert_with(|| text[content..self.idx].to_string());
+                match self.lex_escape(quote)? {
+                    Some(c) => string.push(c),
+                    None => break,
+                }
+            } else if c == '\r' && self.options.normalize_newlines {
+                let string = string.get_or_insert_with(|| text[content..self.idx].to_string());
+                self.skip_newline();
+                string.push('\n');
+            } else {
+                self.advance();
+                if let Some(string) = &mut string {
+                    string.push(c);
+                }
+            }
+        }
+        if !text[self.idx..].starts_with(delimiter) {
+            return Err(Error::new(ErrorType::UnclosedString, pos.clone()));
+        }
+        let string = match string {
+            Some(string) => Cow::Owned(string),
+            None => Cow::Borrowed(&text[content..self.idx]),
+        };
+        for _ in 0..count {
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        if !segments.is_empty() {
+            if !string.is_empty() {
+                segments.push(Segment::Literal(string));
+            }
+            return Ok(Token::Template(segments));
+        }
+        if triple && self.options.dedent {
+            return Ok(Token::String(Cow::Owned(dedent(&string))));
+        }
+        Ok(Token::String(string))
+    }
+    /// Lexes the tokens of an interpolation from its `opener` up to and including the `closer`
+    /// that is not matched by an opening bracket inside it.
+    fn lex_interpolation(
+        &mut self,
+        opener: &str,
+        closer: &str,
+    ) -> Result<Vec<Located<Token<'src>>>, Error> {
+        let mut pos = self.pos();
+        for _ in opener.chars() {
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        let open = match closer.chars().next() {
+            Some('}') => Some('{'),
+            Some(')') => Some('('),
+            Some(']') => Some('['),
+            _ => None,
+        };
+        let mut depth = 0;
+        let mut tokens = vec![];
+        self.after_operand = false;
+        loop {
+            self.skip_whitespace();
+            let rest = &self.text[self.idx..];
+            if rest.is_empty() {
+                let error_type = ErrorType::UnclosedInterpolation(closer.to_string());
+                return Err(Error::new(error_type, pos));
+            }
+            if rest.starts_with(closer) {
+                if depth == 0 {
+                    break;
+                }
+                depth -= 1;
+            } else if open.is_some_and(|open| rest.starts_with(open)) {
+                depth += 1;
+            }
+            tokens.extend(self.next_token()?);
+        }
+        for _ in closer.chars() {
+            self.advance();
+        }
+        Ok(tokens)
+    }
+    /// Checks whether a heredoc introducer and terminator start at the current character.
+    fn is_heredoc(&self) -> bool {
+        let Some(introducer) = self.options.heredoc.as_deref() else {
+            return false;
+        };
+        let Some(rest) = self.text[self.idx..].strip_prefix(introducer) else {
+            return false;
+        };
+        let rest = rest.strip_prefix('-').unwrap_or(rest);
+        !introducer.is_empty() && rest.starts_with(|c| self.is_ident_start(c))
+    }
+    fn lex_heredoc(&mut self, pos: &mut Position) -> Result<Token<'src>, Error> {
+        let text = self.text;
+        let introducer = self.options.heredoc.as_deref().unwrap_or_default();
+        for _ in 0..introducer.chars().count() {
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        let indented = self.get() == Some('-');
+        if indented {
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        let start = self.idx;
+        while let Some(c) = self.get() {
+            if !self.is_ident_continue(c) {
+                break;
+            }
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        let terminator = &text[start..self.idx];
+        // bodies of several heredocs on one line follow each other
+        let body = match &self.heredoc_bodies {
+            Some(bodies) => bodies.end,
+            None => next_line(text, self.idx),
+        };
+        let mut line = body;
+        loop {
+            if line >= text.len() {
+                let error_type = ErrorType::UnterminatedHeredoc(terminator.to_string());
+                return Err(Error::new(error_type, pos.clone()));
+            }
+            let rest = &text[line..];
+            let len = rest.find(['\n', '\r']).unwrap_or(rest.len());
+            let content = match indented {
+                true => rest[..len].trim_start_matches([' ', '\t']),
+                false => &rest[..len],
+            };
+            if content == terminator {
+                let start = self
+                    .heredoc_bodies
+                    .as_ref()
+                    .map_or(body, |bodies| bodies.start);
+                self.heredoc_bodies = Some(start..next_line(text, line));
+                break;
+            }
+            line = next_line(text, line);
+        }
+        let string = &text[body..line];
+        Ok(Token::String(
+            if self.options.normalize_newlines && string.contains('\r') {
+                Cow::Owned(string.replace("\r\n", "\n").replace('\r', "\n"))
+            } else {
+                Cow::Borrowed(string)
+            },
+        ))
+    }
+    /// Returns the quote and the number of `#` if a raw string starts at the current character.
+    fn raw_string(&self) -> Option<(char, usize)> {
+        if !self.options.raw_strings {
+            return None;
+        }
+        let rest = self.text[self.idx..].strip_prefix('r')?;
+        let hashes = rest.len() - rest.trim_start_matches('#').len();
+        let quote = rest[hashes..].chars().next()?;
+        (self.quote_kind(quote) == Some(Quote::String)).then_some((quote, hashes))
+    }
+    fn lex_raw_string(
+        &mut self,
+        quote: char,
+        hashes: usize,
+        pos: &mut Position,
+    ) -> Result<Token<'src>, Error> {
+        let text = self.text;
+        for _ in 0..hashes + 2 {
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        let content = self.idx;
+        let closing = format!("{quote}{}", "#".repeat(hashes));
+        let rest = if self.options.multiline_strings {
+            &text[content..]
+        } else {
+            text[content..].split(['\r', '\n']).next().unwrap()
+        };
+        let Some(len) = rest.find(&closing) else {
+            while self.idx < content + rest.len() {
+                self.advance();
+            }
+            return Err(Error::new(
+                ErrorType::UnclosedRawString(closing),
+                pos.clone(),
+            ));
+        };
+        let end = content + len + closing.len();
+        while self.idx < end {
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        let string = &text[content..content + len];
+        Ok(Token::String(
+            if self.options.normalize_newlines && string.contains('\r') {
+                Cow::Owned(string.replace("\r\n", "\n").replace('\r', "\n"))
+            } else {
+                Cow::Borrowed(string)
+            },
+        ))
+    }
+    fn check_ident(&mut self, ident: &str, pos: &Position) -> Result<(), Error> {
+        if self.options.confusables == Policy::Allow || ident.is_ascii() {
+            return Ok(());
+        }
+        let error_type = if unicode::is_mixed_script(ident) {
+            ErrorType::MixedScript(ident.to_string())
+        } else if let Some(skeleton) = unicode::latin_skeleton(ident) {
+            ErrorType::Confusable(ident.to_string(), skeleton)
+        } else {
+            return Ok(());
+        };
+        self.report(
+            self.options.confusables,
+            Error::new(error_type, pos.clone()),
+        )
+    }
+    pub fn skip_whitespace(&mut self) {
+        loop {
+            match self.get() {
+                Some(' ' | '\t') => self.advance(),
+                Some('\r' | '\n') => {
+                    self.end_line();
+                }
+                _ => break,
+            }
+        }
+    }
+    /// Skips a leading byte-order mark and handles a shebang line, if at the start of the text.
+    pub fn lex_prelude(&mut self) -> Result<Option<Located<Token<'src>>>, Error> {
+        if self.idx != 0 {
+            return Ok(None);
+        }
+        if self.options.skip_bom && self.get() == Some('\u{feff}') {
+            self.idx += '\u{feff}'.len_utf8();
+        }
+        if self.options.shebang == Shebang::Lex || !self.text[self.idx..].starts_with("#!") {
+            return Ok(None);
+        }
+        let text = self.text;
+        let start = self.idx;
+        let mut pos = self.pos();
+        self.advance();
+        while let Some(c) = self.get() {
+            if let '\n' | '\r' = c {
+                break;
+            }
+            pos.extend(&self.pos());
+            self.advance();
+        }
+        if let Some(error) = self.denied.take() {
+            return Err(error);
+        }
+        Ok(match self.options.shebang {
+            Shebang::Token => Some(Located::new(
+                Token::Shebang(Cow::Borrowed(&text[start + 2..self.idx])),
+                pos,
+            )),
+            _ => None,
+        })
+    }
+    pub fn lex(&mut self) -> Result<Vec<Located<Token<'src>>>, Error> {
+        let mut tokens: Vec<_> = self.lex_prelude()?.into_iter().collect();
+        loop {
+            self.skip_whitespace();
+            match self.next_token()? {
+                Some(token) => tokens.push(token),
+                None => break,
+            }
+        }
+        Ok(tokens)
+    }
+    /// Lexes the token starting at the current character, which must not be whitespace.
+    pub fn next_token(&mut self) -> Result<Option<Located<Token<'src>>>, Error> {
+        let token = self.lex_token()?;
+        if let Some(error) = self.denied.take() {
+            return Err(error);
+        }
+        if let (Some(token), Some(bodies)) = (&token, &self.heredoc_bodies) {
+            if self.idx >= bodies.start {
+                return Err(Error::new(ErrorType::HeredocInLiteral, token.pos.clone()));
+            }
+        }
+        if let Some(token) = &token {
+            self.after_operand = matches!(
+                token.value,
+                Token::Ident(_)
+                    | Token::Int(..)
+                    | Token::WideInt(..)
+                    | Token::BigInt(_)
+                    | Token::Float(_)
+                    | Token::Char(_)
+                    | Token::String(_)
+                    | Token::Byte(_)
+                    | Token::ByteString(_)
+                    | Token::Suffixed(..)
+                    | Token::Template(_)
+                    | Token::Symbol(')' | ']' | '}')
+            );
+        }
+        Ok(token)
+    }
+    fn lex_token(&mut self) -> Result<Option<Located<Token<'src>>>, Error> {
+        let Some(c) = self.get() else {
+            return Ok(None);
+        };
+        let text = self.text;
+        let start = self.idx;
+        let mut pos = self.pos();
+        if unicode::is_bidi_control(c) && self.options.bidi_controls == Policy::Deny {
+            return Err(Error::new(ErrorType::BidiControl(c), pos));
+        }
+        let token = match c {
+            _ if self.is_heredoc() => self.lex_heredoc(&mut pos)?,
+            '0'..='9' => self.lex_number(start, &mut pos)?,
+            '.' if self.options.leading_dot_floats
+                && self.text[self.idx + 1..].starts_with(|c: char| c.is_ascii_digit()) =>
+            {
+                self.lex_number(start, &mut pos)?
+            }
+            '+' | '-' if self.is_signed_number() => {
+                self.advance();
+                match self.lex_special_float(start, &mut pos) {
+                    Some(token) => token,
+                    None => self.lex_number(start, &mut pos)?,
+                }
+            }
+            'b' if self.byte_literal().is_some() => match self.byte_literal().unwrap() {
+                (quote, Quote::Char) => self.lex_byte(quote, &mut pos)?,
+                (quote, _) => self.lex_byte_string(quote, &mut pos)?,
+            },
+            'r' if self.raw_string().is_some() => {
+                let (quote, hashes) = self.raw_string().unwrap();
+                self.lex_raw_string(quote, hashes, &mut pos)?
+            }
+            _ if self.special_float(start).is_some() => {
+                self.lex_special_float(start, &mut pos).unwrap()
+            }
+            c if self.is_ident_start(c) => {
+                self.advance();
+                while let Some(c) = self.get() {
+                    if !self.is_ident_continue(c) {
+                        break;
                     }
                     pos.extend(&self.pos());
-                    if self.next_char().unwrap() != '\'' {
-                        return Err(Error::new(ErrorType::BadChar(c), pos));
-                    }
-                    tokens.push(Located::new(Token::Char(c), pos));
+                    self.advance();
                 }
-                '"' => {
+                let ident = &text[start..self.idx];
+                self.check_ident(ident, &pos)?;
+                if self.options.keywords.iter().any(|keyword| keyword == ident) {
+                    Token::Keyword(Cow::Borrowed(ident))
+                } else {
+                    Token::Ident(Cow::Borrowed(ident))
+                }
+            }
+            c if self.quote_kind(c) == Some(Quote::Char) => self.lex_char(c, &mut pos)?,
+            c if self.quote_kind(c) == Some(Quote::String) => {
+                let triple = self.options.triple_quoted_strings
+                    && text[start..].chars().take(3).filter(|&q| q == c).count() == 3;
+                self.lex_string(c, triple, &mut pos)?
+            }
+            c if self.quote_kind(c) == Some(Quote::Ident) => self.lex_quoted_ident(c, &mut pos)?,
+            c if !c.is_ascii() && !self.options.symbols.iter().any(|s| s.starts_with(c)) => {
+                // a bidi control outside a literal is an error whatever the policy
+                let error_type = match unicode::is_bidi_control(c) {
+                    true => ErrorType::BidiControl(c),
+                    false => ErrorType::BadChar(c),
+                };
+                return Err(Error::new(error_type, pos));
+            }
+            _ => {
+                if self.has_symbols() {
+                    let Some(symbol) = self.match_symbol() else {
+                        return Err(Error::new(ErrorType::InvalidSymbol(c.to_string()), pos));
+                    };
                     self.advance();
-                    let mut string = String::new();
-                    while let Some(c) = self.get() {
-                        if c == '"' {
-                            break;
-                        }
-                        let c = self.next_char().unwrap();
-                        if c == '\\' {
-                            let c = match self.next_char().unwrap() {
-                                'n' => '\n',
-                                't' => '\t',
-                                'r' => '\r',
-                                '\\' => '\\',
-                                '"' => '"',
-                                _ => return Err(Error::new(ErrorType::BadChar(c), pos)),
-                            };
-                            string.push(c);
-                        } else {
-                            string.push(c);
-                        }
-                    }
-                    if let Some('"') = self.get() {
+                    for _ in symbol.chars().skip(1) {
                         pos.extend(&self.pos());
                         self.advance();
-                        tokens.push(Located::new(Token::String(string), pos));
-                    } else {
-                        return Err(Error::new(ErrorType::UnclosedString, pos));
                     }
-                }
-                _ => {
-                    if self.has_symbols() {
-                        let mut symbol = self.next_char().unwrap().to_string();
-                        while self.get().is_some() {
-                            let matches = self
-                                .symbols
-                                .iter()
-                                .filter(|s| *s == &symbol || s.starts_with(&symbol))
-                                .count();
-                            if matches == 0 {
-                                symbol.pop();
-                                break;
-                            } else if matches == 1 {
-                                break;
-                            } else {
-                                symbol.push(self.next_char().unwrap());
-                                pos.extend(&self.pos());
-                            }
-                        }
-                        if self.symbols.iter().any(|s| s == &symbol) {
-                            if symbol.len() == 1 {
-                                tokens.push(Located::new(
-                                    Token::Symbol(symbol.chars().next().unwrap()),
-                                    pos,
-                                ));
-                            } else {
-                                tokens.push(Located::new(Token::LongSymbol(symbol), pos));
-                            }
-                        } else {
-                            return Err(Error::new(ErrorType::InvalidSymbol(symbol), pos));
-                        }
-                    } else {
-                        let c = self.next_char().unwrap();
-                        tokens.push(Located::new(Token::Symbol(c), pos));
+                    let mut chars = symbol.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Token::Symbol(c),
+                        _ => Token::LongSymbol(Cow::Borrowed(symbol)),
                     }
+                } else {
+                    Token::Symbol(self.next_char().unwrap())
                 }
             }
-        }
-        Ok(tokens)
+        };
+        Ok(Some(Located::new(token, pos)))
+    }
+}
+/// Removes the newline at the start of `string`, the indentation common to its non-blank lines
+/// and all whitespace on its blank lines.
+fn dedent(string: &str) -> String {
+    let string = string
+        .strip_prefix("\r\n")
+        .or_else(|| string.strip_prefix(['\n', '\r']))
+        .unwrap_or(string);
+    let blank = |line: &str| line.trim().is_empty();
+    // the longest whitespace prefix shared by the non-blank lines, so tabs and spaces never match
+    let indent = string
+        .split('\n')
+        .filter(|line| !blank(line))
+        .map(|line| &line[..line.len() - line.trim_start_matches([' ', '\t']).len()])
+        .reduce(|indent, prefix| {
+            let len = indent
+                .bytes()
+                .zip(prefix.bytes())
+                .take_while(|(a, b)| a == b)
+                .count();
+            &indent[..len]
+        })
+        .map_or(0, str::len);
+    let lines: Vec<_> = string
+        .split('\n')
+        .map(|line| match blank(line) {
+            true if line.ends_with('\r') => "\r",
+            true => "",
+            false => &line[indent..],
+        })
+        .collect();
+    lines.join("\n")
+}
+/// Returns the index of the line after the one containing `idx`.
+fn next_line(text: &str, idx: usize) -> usize {
+    let rest = &text[idx..];
+    match rest.find(['\n', '\r']) {
+        Some(len) if rest[len..].starts_with("\r\n") => idx + len + 2,
+        Some(len) => idx + len + 1,
+        None => text.len(),
     }
 }
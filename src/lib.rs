@@ -4,11 +4,18 @@ mod tests;
 pub mod error;
 pub mod indent_lexer;
 pub mod lexer;
+pub mod stream_lexer;
 pub mod tokens;
+pub mod unicode;
 
-pub fn lex(input: String) -> Result<Vec<error::Located<tokens::Token>>, error::Error> {
+pub fn lex(input: &str) -> Result<Vec<error::Located<tokens::Token<'_>>>, error::Error> {
     lexer::Lexer::new(input).lex()
 }
-pub fn lex_indent(input: String) -> Result<Vec<indent_lexer::Line>, error::Error> {
+pub fn lex_stream<R: std::io::Read>(
+    reader: R,
+) -> Result<Vec<error::Located<tokens::Token<'static>>>, error::Error> {
+    stream_lexer::Lexer::new(reader).lex()
+}
+pub fn lex_indent(input: &str) -> Result<Vec<indent_lexer::Line<'_>>, error::Error> {
     indent_lexer::Lexer::new(input).lex()
 }
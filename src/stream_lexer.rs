@@ -0,0 +1,337 @@
+use std::io::{ErrorKind, Read};
+
+use crate::error::{Error, ErrorType, Located, Position};
+use crate::lexer::{self, DecimalPoint, IntWidth, Policy, Quote, Shebang};
+use crate::tokens::Token;
+
+/// How many bytes past the end of a token have to be buffered before the token is final.
+pub const LOOKAHEAD: usize = 64;
+
+/// Lexes tokens out of a reader while holding at most `capacity` bytes of unlexed input,
+/// plus whatever a single token needs beyond that.
+pub struct Lexer<R> {
+    pub reader: R,
+    /// Holds the options and the position of the first unlexed byte; its text is always empty.
+    pub lexer: lexer::Lexer<'static>,
+    pub capacity: usize,
+    buffer: String,
+    start: usize,
+    chunk: Vec<u8>,
+    bytes: Vec<u8>,
+    eof: bool,
+    invalid: bool,
+    done: bool,
+}
+impl<R: Read> Lexer<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            lexer: lexer::Lexer::new(""),
+            capacity: 8 * 1024,
+            buffer: String::new(),
+            start: 0,
+            chunk: vec![],
+            bytes: vec![],
+            eof: false,
+            invalid: false,
+            done: false,
+        }
+    }
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+    pub fn symbols(mut self, symbols: &[&str]) -> Self {
+        self.lexer = self.lexer.symbols(symbols);
+        self
+    }
+    pub fn keywords(mut self, keywords: &[&str]) -> Self {
+        self.lexer = self.lexer.keywords(keywords);
+        self
+    }
+    pub fn unicode_idents(mut self, unicode_idents: bool) -> Self {
+        self.lexer = self.lexer.unicode_idents(unicode_idents);
+        self
+    }
+    pub fn ident_start(mut self, f: impl Fn(char) -> bool + 'static) -> Self {
+        self.lexer = self.lexer.ident_start(f);
+        self
+    }
+    pub fn ident_continue(mut self, f: impl Fn(char) -> bool + 'static) -> Self {
+        self.lexer = self.lexer.ident_continue(f);
+        self
+    }
+    pub fn ident_start_chars(mut self, chars: &str) -> Self {
+        self.lexer = self.lexer.ident_start_chars(chars);
+        self
+    }
+    pub fn ident_continue_chars(mut self, chars: &str) -> Self {
+        self.lexer = self.lexer.ident_continue_chars(chars);
+        self
+    }
+    pub fn skip_bom(mut self, skip_bom: bool) -> Self {
+        self.lexer = self.lexer.skip_bom(skip_bom);
+        self
+    }
+    pub fn shebang(mut self, shebang: Shebang) -> Self {
+        self.lexer = self.lexer.shebang(shebang);
+        self
+    }
+    pub fn normalize_newlines(mut self, normalize_newlines: bool) -> Self {
+        self.lexer = self.lexer.normalize_newlines(normalize_newlines);
+        self
+    }
+    pub fn bidi_controls(mut self, policy: Policy) -> Self {
+        self.lexer = self.lexer.bidi_controls(policy);
+        self
+    }
+    pub fn confusables(mut self, policy: Policy) -> Self {
+        self.lexer = self.lexer.confusables(policy);
+        self
+    }
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.lexer = self.lexer.tab_width(tab_width);
+        self
+    }
+    pub fn radix_prefixes(mut self, radix_prefixes: bool) -> Self {
+        self.lexer = self.lexer.radix_prefixes(radix_prefixes);
+        self
+    }
+    pub fn digit_separator(mut self, separator: char) -> Self {
+        self.lexer = self.lexer.digit_separator(separator);
+        self
+    }
+    pub fn exponents(mut self, exponents: bool) -> Self {
+        self.lexer = self.lexer.exponents(exponents);
+        self
+    }
+    pub fn int_width(mut self, int_width: IntWidth) -> Self {
+        self.lexer = self.lexer.int_width(int_width);
+        self
+    }
+    pub fn suffixes(mut self, suffixes: &[&str]) -> Self {
+        self.lexer = self.lexer.suffixes(suffixes);
+        self
+    }
+    pub fn strict_suffixes(mut self, strict_suffixes: bool) -> Self {
+        self.lexer = self.lexer.strict_suffixes(strict_suffixes);
+        self
+    }
+    pub fn decimal_point(mut self, decimal_point: DecimalPoint) -> Self {
+        self.lexer = self.lexer.decimal_point(decimal_point);
+        self
+    }
+    pub fn leading_dot_floats(mut self, leading_dot_floats: bool) -> Self {
+        self.lexer = self.lexer.leading_dot_floats(leading_dot_floats);
+        self
+    }
+    pub fn signed_numbers(mut self, signed_numbers: bool) -> Self {
+        self.lexer = self.lexer.signed_numbers(signed_numbers);
+        self
+    }
+    pub fn special_floats(mut self, special_floats: &[(&str, f64)]) -> Self {
+        self.lexer = self.lexer.special_floats(special_floats);
+        self
+    }
+    pub fn null_escapes(mut self, null_escapes: bool) -> Self {
+        self.lexer = self.lexer.null_escapes(null_escapes);
+        self
+    }
+    pub fn hex_escapes(mut self, hex_escapes: bool) -> Self {
+        self.lexer = self.lexer.hex_escapes(hex_escapes);
+        self
+    }
+    pub fn unicode_escapes(mut self, unicode_escapes: bool) -> Self {
+        self.lexer = self.lexer.unicode_escapes(unicode_escapes);
+        self
+    }
+    pub fn short_unicode_escapes(mut self, short_unicode_escapes: bool) -> Self {
+        self.lexer = self.lexer.short_unicode_escapes(short_unicode_escapes);
+        self
+    }
+    pub fn raw_strings(mut self, raw_strings: bool) -> Self {
+        self.lexer = self.lexer.raw_strings(raw_strings);
+        self
+    }
+    pub fn multiline_strings(mut self, multiline_strings: bool) -> Self {
+        self.lexer = self.lexer.multiline_strings(multiline_strings);
+        self
+    }
+    pub fn triple_quoted_strings(mut self, triple_quoted_strings: bool) -> Self {
+        self.lexer = self.lexer.triple_quoted_strings(triple_quoted_strings);
+        self
+    }
+    pub fn dedent(mut self, dedent: bool) -> Self {
+        self.lexer = self.lexer.dedent(dedent);
+        self
+    }
+    pub fn interpolation(mut self, opener: &str, closer: &str) -> Self {
+        self.lexer = self.lexer.interpolation(opener, closer);
+        self
+    }
+    pub fn quote(mut self, quote: char, kind: Quote) -> Self {
+        self.lexer = self.lexer.quote(quote, kind);
+        self
+    }
+    pub fn byte_literals(mut self, byte_literals: bool) -> Self {
+        self.lexer = self.lexer.byte_literals(byte_literals);
+        self
+    }
+    pub fn lifetimes(mut self, lifetimes: bool) -> Self {
+        self.lexer = self.lexer.lifetimes(lifetimes);
+        self
+    }
+    pub fn escapes(mut self, escapes: &[(char, char)]) -> Self {
+        self.lexer = self.lexer.escapes(escapes);
+        self
+    }
+    pub fn escape_decoder(mut self, f: impl Fn(&str) -> Option<(char, usize)> + 'static) -> Self {
+        self.lexer = self.lexer.escape_decoder(f);
+        self
+    }
+    pub fn unknown_escapes(mut self, policy: Policy) -> Self {
+        self.lexer = self.lexer.unknown_escapes(policy);
+        self
+    }
+    pub fn pos(&self) -> Position {
+        self.lexer.pos()
+    }
+    fn lookahead(&self) -> usize {
+        let longest = self.lexer.options.symbols.iter().map(String::len).max();
+        LOOKAHEAD.max(longest.unwrap_or(0))
+    }
+    /// Reads up to `capacity` more bytes, dropping the part of the buffer that was already lexed.
+    fn fill(&mut self) -> Result<(), Error> {
+        self.buffer.drain(..self.start);
+        self.start = 0;
+        self.chunk.resize(self.capacity, 0);
+        let read = loop {
+            match self.reader.read(&mut self.chunk) {
+                Ok(read) => break read,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(Error::new(ErrorType::Io(err.to_string()), self.pos())),
+            }
+        };
+        if read == 0 {
+            // an incomplete sequence at the very end can never be completed
+            self.eof = true;
+            self.invalid = !self.bytes.is_empty();
+            return Ok(());
+        }
+        self.bytes.extend_from_slice(&self.chunk[..read]);
+        let valid = match std::str::from_utf8(&self.bytes) {
+            Ok(_) => self.bytes.len(),
+            Err(err) => {
+                if err.error_len().is_some() {
+                    // nothing after invalid bytes gets lexed, so stop reading
+                    self.eof = true;
+                    self.invalid = true;
+                }
+                err.valid_up_to()
+            }
+        };
+        let text = std::str::from_utf8(&self.bytes[..valid]).unwrap();
+        self.buffer.push_str(text);
+        self.bytes.drain(..valid);
+        Ok(())
+    }
+    pub fn next_token(&mut self) -> Result<Option<Located<Token<'static>>>, Error> {
+        if self.done {
+            return Ok(None);
+        }
+        let result = self.step();
+        if !matches!(result, Ok(Some(_))) {
+            self.done = true;
+        }
+        result
+    }
+    fn step(&mut self) -> Result<Option<Located<Token<'static>>>, Error> {
+        let lookahead = self.lookahead();
+        loop {
+            let options = std::mem::take(&mut self.lexer.options);
+            let mut lexer = lexer::Lexer::with_options(&self.buffer[self.start..], options);
+            lexer.ln = self.lexer.ln;
+            lexer.col = self.lexer.col;
+            lexer.vcol = self.lexer.vcol;
+            lexer.after_operand = self.lexer.after_operand;
+            lexer.open_flag = self.lexer.open_flag;
+            let prelude = if self.lexer.idx == 0 {
+                lexer.lex_prelude()
+            } else {
+                Ok(None)
+            };
+            let result = match prelude {
+                Ok(None) => {
+                    lexer.skip_whitespace();
+                    lexer.next_token()
+                }
+                result => result,
+            }
+            .map(|token| token.map(|token| Located::new(token.value.into_owned(), token.pos)));
+            let (idx, ln, col, vcol) = (lexer.idx, lexer.ln, lexer.col, lexer.vcol);
+            let (after_operand, open_flag) = (lexer.after_operand, lexer.open_flag);
+            let warnings = std::mem::take(&mut lexer.warnings);
+            self.lexer.options = lexer.options;
+            if !self.eof && self.start + idx + lookahead > self.buffer.len() {
+                // at least double the unlexed input, so that a long token is lexed again
+                // only a logarithmic number of times
+                let target = 2 * (self.buffer.len() - self.start);
+                loop {
+                    self.fill()?;
+                    if self.eof || self.buffer.len() - self.start >= target {
+                        break;
+                    }
+                }
+                continue;
+            }
+            let base = self.lexer.idx;
+            if self.invalid
+                && self.start + idx == self.buffer.len()
+                && !matches!(result, Ok(Some(_)))
+            {
+                let mut pos = Position::new(base + idx..base + idx + 1, ln..ln + 1, col..col + 1);
+                pos.vcol = vcol..vcol + 1;
+                return Err(Error::new(ErrorType::InvalidUtf8, pos));
+            }
+            self.start += idx;
+            self.lexer.idx += idx;
+            self.lexer.ln = ln;
+            self.lexer.col = col;
+            self.lexer.vcol = vcol;
+            self.lexer.after_operand = after_operand;
+            self.lexer.open_flag = open_flag;
+            self.lexer
+                .warnings
+                .extend(warnings.into_iter().map(|mut warning| {
+                    offset(&mut warning.pos, base);
+                    warning
+                }));
+            return match result {
+                Ok(token) => Ok(token.map(|mut token| {
+                    offset(&mut token.pos, base);
+                    token
+                })),
+                Err(mut err) => {
+                    offset(&mut err.pos, base);
+                    Err(err)
+                }
+            };
+        }
+    }
+    pub fn lex(&mut self) -> Result<Vec<Located<Token<'static>>>, Error> {
+        let mut tokens = vec![];
+        while let Some(token) = self.next_token()? {
+            tokens.push(token);
+        }
+        Ok(tokens)
+    }
+}
+impl<R: Read> Iterator for Lexer<R> {
+    type Item = Result<Located<Token<'static>>, Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token().transpose()
+    }
+}
+fn offset(pos: &mut Position, base: usize) {
+    pos.idx = pos.idx.start + base..pos.idx.end + base;
+}
@@ -1,69 +1,69 @@
 #[test]
 fn simple() {
     use crate::lexer::Lexer;
-    use crate::tokens::Token;
-    let mut lexer = Lexer::new("1 + 2".to_string());
+    use crate::tokens::{Radix, Token};
+    let mut lexer = Lexer::new("1 + 2");
     let tokens = lexer.lex().unwrap();
     assert_eq!(tokens.len(), 3);
-    assert_eq!(tokens[0].value, Token::Int(1));
+    assert_eq!(tokens[0].value, Token::Int(1, Radix::Decimal));
     assert_eq!(tokens[1].value, Token::Symbol('+'));
-    assert_eq!(tokens[2].value, Token::Int(2));
+    assert_eq!(tokens[2].value, Token::Int(2, Radix::Decimal));
 }
 #[test]
 fn symbols() {
     use crate::lexer::Lexer;
-    use crate::tokens::Token;
-    let mut lexer = Lexer::new("1 + 2 ++ ".to_string()).symbols(&["+", "++"]);
+    use crate::tokens::{Radix, Token};
+    let mut lexer = Lexer::new("1 + 2 ++ ").symbols(&["+", "++"]);
     let tokens = lexer.lex().unwrap();
     assert_eq!(tokens.len(), 4);
-    assert_eq!(tokens[0].value, Token::Int(1));
+    assert_eq!(tokens[0].value, Token::Int(1, Radix::Decimal));
     assert_eq!(tokens[1].value, Token::Symbol('+'));
-    assert_eq!(tokens[2].value, Token::Int(2));
-    assert_eq!(tokens[3].value, Token::LongSymbol("++".to_string()));
+    assert_eq!(tokens[2].value, Token::Int(2, Radix::Decimal));
+    assert_eq!(tokens[3].value, Token::LongSymbol("++".into()));
 }
 #[test]
 fn string() {
     use crate::lexer::Lexer;
     use crate::tokens::Token;
-    let mut lexer = Lexer::new(" \"hello\"".to_string());
+    let mut lexer = Lexer::new(" \"hello\"");
     let tokens = lexer.lex().unwrap();
     assert_eq!(tokens.len(), 1);
-    assert_eq!(tokens[0].value, Token::String("hello".to_string()));
+    assert_eq!(tokens[0].value, Token::String("hello".into()));
 }
 #[test]
 fn string_indent() {
     use crate::indent_lexer::Lexer;
     use crate::tokens::Token;
-    let mut lexer = Lexer::new(" \"hello\"".to_string());
+    let mut lexer = Lexer::new(" \"hello\"");
     let lines = lexer.lex().unwrap();
     assert_eq!(lines[0].len(), 1);
-    assert_eq!(lines[0].tokens[0].value, Token::String("hello".to_string()));
+    assert_eq!(lines[0].tokens[0].value, Token::String("hello".into()));
 }
 #[test]
 fn strings() {
     use crate::lexer::Lexer;
     use crate::tokens::Token;
-    let mut lexer = Lexer::new(" \"hello\"\"there\" ".to_string());
+    let mut lexer = Lexer::new(" \"hello\"\"there\" ");
     let tokens = lexer.lex().unwrap();
     assert_eq!(tokens.len(), 2);
-    assert_eq!(tokens[0].value, Token::String("hello".to_string()));
-    assert_eq!(tokens[1].value, Token::String("there".to_string()));
+    assert_eq!(tokens[0].value, Token::String("hello".into()));
+    assert_eq!(tokens[1].value, Token::String("there".into()));
 }
 #[test]
 fn strings_indent() {
     use crate::indent_lexer::Lexer;
     use crate::tokens::Token;
-    let mut lexer = Lexer::new(" \"hello\"\"there\" ".to_string());
+    let mut lexer = Lexer::new(" \"hello\"\"there\" ");
     let lines = lexer.lex().unwrap();
     assert_eq!(lines[0].len(), 2);
-    assert_eq!(lines[0].tokens[0].value, Token::String("hello".to_string()));
-    assert_eq!(lines[0].tokens[1].value, Token::String("there".to_string()));
+    assert_eq!(lines[0].tokens[0].value, Token::String("hello".into()));
+    assert_eq!(lines[0].tokens[1].value, Token::String("there".into()));
 }
 #[test]
 fn chars() {
     use crate::lexer::Lexer;
     use crate::tokens::Token;
-    let mut lexer = Lexer::new(" 'h''e' ".to_string());
+    let mut lexer = Lexer::new(" 'h''e' ");
     let tokens = lexer.lex().unwrap();
     assert_eq!(tokens.len(), 2);
     assert_eq!(tokens[0].value, Token::Char('h'));
@@ -73,7 +73,7 @@ fn chars() {
 fn chars_indent() {
     use crate::indent_lexer::Lexer;
     use crate::tokens::Token;
-    let mut lexer = Lexer::new(" 'h''e' ".to_string());
+    let mut lexer = Lexer::new(" 'h''e' ");
     let lines = lexer.lex().unwrap();
     assert_eq!(lines[0].len(), 2);
     assert_eq!(lines[0].tokens[0].value, Token::Char('h'));
@@ -82,34 +82,1496 @@ fn chars_indent() {
 #[test]
 fn keywords() {
     use crate::lexer::Lexer;
-    use crate::tokens::Token;
-    let mut lexer = Lexer::new("local a = 1".to_string())
+    use crate::tokens::{Radix, Token};
+    let mut lexer = Lexer::new("local a = 1")
         .symbols(&["="])
         .keywords(&["local"]);
     let tokens = lexer.lex().unwrap();
     assert_eq!(tokens.len(), 4);
-    assert_eq!(tokens[0].value, Token::Keyword("local".to_string()));
-    assert_eq!(tokens[1].value, Token::Ident("a".to_string()));
+    assert_eq!(tokens[0].value, Token::Keyword("local".into()));
+    assert_eq!(tokens[1].value, Token::Ident("a".into()));
     assert_eq!(tokens[2].value, Token::Symbol('='));
-    assert_eq!(tokens[3].value, Token::Int(1));
+    assert_eq!(tokens[3].value, Token::Int(1, Radix::Decimal));
 }
 #[test]
 fn simple_indent() {
     use crate::indent_lexer::Lexer;
-    use crate::tokens::Token;
-    let mut lexer = Lexer::new("    1 + 2\n2 3 4".to_string());
+    use crate::tokens::{Radix, Token};
+    let mut lexer = Lexer::new("    1 + 2\n2 3 4");
     let lines = lexer.lex().unwrap();
     assert_eq!(lines.len(), 2);
 
     assert_eq!(lines[0].len(), 3);
     assert_eq!(lines[0].indent, 4);
-    assert_eq!(lines[0].tokens[0].value, Token::Int(1));
+    assert_eq!(lines[0].tokens[0].value, Token::Int(1, Radix::Decimal));
     assert_eq!(lines[0].tokens[1].value, Token::Symbol('+'));
-    assert_eq!(lines[0].tokens[2].value, Token::Int(2));
+    assert_eq!(lines[0].tokens[2].value, Token::Int(2, Radix::Decimal));
 
     assert_eq!(lines[1].len(), 3);
     assert_eq!(lines[1].indent, 0);
-    assert_eq!(lines[1].tokens[0].value, Token::Int(2));
-    assert_eq!(lines[1].tokens[1].value, Token::Int(3));
-    assert_eq!(lines[1].tokens[2].value, Token::Int(4));
+    assert_eq!(lines[1].tokens[0].value, Token::Int(2, Radix::Decimal));
+    assert_eq!(lines[1].tokens[1].value, Token::Int(3, Radix::Decimal));
+    assert_eq!(lines[1].tokens[2].value, Token::Int(4, Radix::Decimal));
+}
+#[test]
+fn utf8() {
+    use crate::lexer::Lexer;
+    use crate::tokens::{Radix, Token};
+    let mut lexer = Lexer::new("\"héllo wörld 🎉\" 'λ' 1");
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[0].value, Token::String("héllo wörld 🎉".into()));
+    assert_eq!(tokens[1].value, Token::Char('λ'));
+    assert_eq!(tokens[2].value, Token::Int(1, Radix::Decimal));
+    assert_eq!(tokens[2].pos.idx, 26..27);
+    assert_eq!(tokens[2].pos.col, 20..21);
+}
+#[test]
+fn utf8_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::tokens::{Radix, Token};
+    let mut lexer = Lexer::new("\"ä\"\n  'ö' 2");
+    let lines = lexer.lex().unwrap();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].tokens[0].value, Token::String("ä".into()));
+    assert_eq!(lines[1].indent, 2);
+    assert_eq!(lines[1].tokens[0].value, Token::Char('ö'));
+    assert_eq!(lines[1].tokens[1].value, Token::Int(2, Radix::Decimal));
+    assert_eq!(lines[1].tokens[1].pos.col, 6..7);
+}
+#[test]
+fn utf8_bad_char() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    let mut lexer = Lexer::new("a é");
+    let error = lexer.lex().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::BadChar('é'));
+    assert_eq!(error.pos.idx, 2..4);
+}
+#[test]
+fn utf8_symbols() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new("a → b").symbols(&["→"]);
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[1].value, Token::Symbol('→'));
+}
+#[test]
+fn unicode_idents() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new("größe 変数 π_2 переменная x١").unicode_idents(true);
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens.len(), 5);
+    assert_eq!(tokens[0].value, Token::Ident("größe".into()));
+    assert_eq!(tokens[1].value, Token::Ident("変数".into()));
+    assert_eq!(tokens[2].value, Token::Ident("π_2".into()));
+    assert_eq!(tokens[3].value, Token::Ident("переменная".into()));
+    assert_eq!(tokens[4].value, Token::Ident("x١".into()));
+}
+#[test]
+fn unicode_idents_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new("λ\n  αβγ δ").unicode_idents(true);
+    let lines = lexer.lex().unwrap();
+    assert_eq!(lines[0].tokens[0].value, Token::Ident("λ".into()));
+    assert_eq!(lines[1].indent, 2);
+    assert_eq!(lines[1].tokens[0].value, Token::Ident("αβγ".into()));
+    assert_eq!(lines[1].tokens[1].value, Token::Ident("δ".into()));
+}
+#[test]
+fn unicode_idents_disabled() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    let mut lexer = Lexer::new("größe");
+    let error = lexer.lex().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::BadChar('ö'));
+}
+#[test]
+fn xid_tables() {
+    use crate::unicode::{is_xid_continue, is_xid_start};
+    assert!(is_xid_start('ß'));
+    assert!(is_xid_start('字'));
+    assert!(is_xid_start('ㄱ'));
+    assert!(!is_xid_start('١'));
+    assert!(!is_xid_start('_'));
+    assert!(is_xid_continue('١'));
+    assert!(is_xid_continue('\u{301}'));
+    assert!(!is_xid_continue('→'));
+    assert!(!is_xid_continue('🎉'));
+}
+#[test]
+fn ident_chars() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new("$el + @attr #tag empty? set-car! x-1")
+        .symbols(&["+", "++"])
+        .ident_start_chars("$@#")
+        .ident_continue_chars("$-?!");
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens.len(), 7);
+    assert_eq!(tokens[0].value, Token::Ident("$el".into()));
+    assert_eq!(tokens[1].value, Token::Symbol('+'));
+    assert_eq!(tokens[2].value, Token::Ident("@attr".into()));
+    assert_eq!(tokens[3].value, Token::Ident("#tag".into()));
+    assert_eq!(tokens[4].value, Token::Ident("empty?".into()));
+    assert_eq!(tokens[5].value, Token::Ident("set-car!".into()));
+    assert_eq!(tokens[6].value, Token::Ident("x-1".into()));
+}
+#[test]
+fn ident_predicates() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new("FOO_BAR+foo")
+        .symbols(&["+"])
+        .ident_start(|c| c.is_ascii_uppercase())
+        .ident_continue(|c| c.is_ascii_uppercase() || c == '_');
+    let error = lexer.lex().unwrap_err();
+    assert_eq!(error.pos.idx, 8..9);
+    let mut lexer = Lexer::new("FOO_BAR+BAZ")
+        .symbols(&["+"])
+        .ident_start(|c| c.is_ascii_uppercase())
+        .ident_continue(|c| c.is_ascii_uppercase() || c == '_');
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[0].value, Token::Ident("FOO_BAR".into()));
+    assert_eq!(tokens[2].value, Token::Ident("BAZ".into()));
+}
+#[test]
+fn ident_chars_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new("  $x=$y")
+        .symbols(&["=", "=="])
+        .ident_start_chars("$");
+    let lines = lexer.lex().unwrap();
+    assert_eq!(lines[0].indent, 2);
+    assert_eq!(lines[0].len(), 3);
+    assert_eq!(lines[0].tokens[0].value, Token::Ident("$x".into()));
+    assert_eq!(lines[0].tokens[1].value, Token::Symbol('='));
+    assert_eq!(lines[0].tokens[2].value, Token::Ident("$y".into()));
+}
+#[test]
+fn symbols_longest_match() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new("a+=b===c").symbols(&["+", "+=", "==="]);
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens.len(), 5);
+    assert_eq!(tokens[1].value, Token::LongSymbol("+=".into()));
+    assert_eq!(tokens[2].value, Token::Ident("b".into()));
+    assert_eq!(tokens[3].value, Token::LongSymbol("===".into()));
+    assert_eq!(tokens[3].pos.idx, 4..7);
+    assert_eq!(tokens[4].value, Token::Ident("c".into()));
+}
+#[test]
+fn borrowed() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    use std::borrow::Cow;
+    let source = String::from("let name = \"plain\" \"esc\\taped\" ++");
+    let mut lexer = Lexer::new(&source).symbols(&["=", "++"]).keywords(&["let"]);
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens.len(), 6);
+    assert!(matches!(
+        tokens[0].value,
+        Token::Keyword(Cow::Borrowed("let"))
+    ));
+    assert!(matches!(
+        tokens[1].value,
+        Token::Ident(Cow::Borrowed("name"))
+    ));
+    assert!(matches!(
+        tokens[3].value,
+        Token::String(Cow::Borrowed("plain"))
+    ));
+    assert!(matches!(&tokens[4].value, Token::String(Cow::Owned(s)) if s == "esc\taped"));
+    assert!(matches!(
+        tokens[5].value,
+        Token::LongSymbol(Cow::Borrowed("++"))
+    ));
+    let owned: Vec<Token<'static>> = tokens.into_iter().map(|t| t.value.into_owned()).collect();
+    drop(source);
+    assert_eq!(owned[1], Token::Ident("name".into()));
+}
+#[test]
+fn stream() {
+    use crate::lexer;
+    use crate::stream_lexer::Lexer;
+    let text = "let größe = \"héllo\\n wörld\" ++ 'λ'\n  12.5 + 300 → x_1\n\"🎉\"";
+    let expected = lexer::Lexer::new(text)
+        .symbols(&["=", "+", "++", "→"])
+        .keywords(&["let"])
+        .unicode_idents(true)
+        .lex()
+        .unwrap();
+    for capacity in [1, 2, 3, 7, 64, 4096] {
+        let tokens = Lexer::new(text.as_bytes())
+            .capacity(capacity)
+            .symbols(&["=", "+", "++", "→"])
+            .keywords(&["let"])
+            .unicode_idents(true)
+            .lex()
+            .unwrap();
+        assert_eq!(tokens, expected);
+        for (token, expected) in tokens.iter().zip(&expected) {
+            assert_eq!(token.pos, expected.pos);
+        }
+    }
+}
+#[test]
+fn stream_long_token() {
+    use crate::stream_lexer::Lexer;
+    use crate::tokens::Token;
+    let string = "a".repeat(1000);
+    let text = format!("x \"{string}\" y");
+    let tokens: Vec<_> = Lexer::new(text.as_bytes())
+        .capacity(16)
+        .map(|token| token.unwrap().value)
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Ident("x".into()),
+            Token::String(string.into()),
+            Token::Ident("y".into()),
+        ]
+    );
+}
+#[test]
+fn stream_errors() {
+    use crate::error::ErrorType;
+    use crate::stream_lexer::Lexer;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new(&b"ab \n cd\xff ef"[..]).capacity(2);
+    assert_eq!(
+        lexer.next().unwrap().unwrap().value,
+        Token::Ident("ab".into())
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap().value,
+        Token::Ident("cd".into())
+    );
+    let error = lexer.next().unwrap().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::InvalidUtf8);
+    assert_eq!(error.pos.idx, 7..8);
+    assert_eq!(error.pos.ln, 1..2);
+    assert_eq!(error.pos.col, 3..4);
+    assert!(lexer.next().is_none());
+    let error = Lexer::new(&b"\"abc"[..]).capacity(1).lex().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnclosedString);
+    assert_eq!(error.pos.idx, 0..1);
+}
+#[test]
+fn bom() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new("\u{feff}a b").skip_bom(true);
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].value, Token::Ident("a".into()));
+    assert_eq!(tokens[0].pos.idx, 3..4);
+    assert_eq!(tokens[0].pos.col, 0..1);
+    assert!(Lexer::new("\u{feff}a b").lex().is_err());
+}
+#[test]
+fn shebang() {
+    use crate::lexer::{Lexer, Shebang};
+    use crate::tokens::Token;
+    let text = "\u{feff}#!/usr/bin/env ourlang\r\nx";
+    let tokens = Lexer::new(text)
+        .skip_bom(true)
+        .shebang(Shebang::Token)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(
+        tokens[0].value,
+        Token::Shebang("/usr/bin/env ourlang".into())
+    );
+    assert_eq!(tokens[0].pos.idx, 3..25);
+    assert_eq!(tokens[1].value, Token::Ident("x".into()));
+    assert_eq!(tokens[1].pos.idx, 27..28);
+    assert_eq!(tokens[1].pos.ln, 1..2);
+    let tokens = Lexer::new("#!ourlang\nx #!")
+        .shebang(Shebang::Skip)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[0].value, Token::Ident("x".into()));
+    assert_eq!(tokens[1].value, Token::Symbol('#'));
+}
+#[test]
+fn shebang_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::lexer::Shebang;
+    use crate::tokens::Token;
+    let mut lexer = Lexer::new("#!/bin/ourlang\n  x")
+        .skip_bom(true)
+        .shebang(Shebang::Token);
+    let lines = lexer.lex().unwrap();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[0].tokens[0].value,
+        Token::Shebang("/bin/ourlang".into())
+    );
+    assert_eq!(lines[1].indent, 2);
+    assert_eq!(lines[1].tokens[0].value, Token::Ident("x".into()));
+}
+#[test]
+fn shebang_stream() {
+    use crate::lexer::Shebang;
+    use crate::stream_lexer::Lexer;
+    use crate::tokens::Token;
+    let text = format!("\u{feff}#!/usr/bin/{}\nx", "a".repeat(200));
+    let tokens = Lexer::new(text.as_bytes())
+        .capacity(3)
+        .skip_bom(true)
+        .shebang(Shebang::Skip)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].value, Token::Ident("x".into()));
+    assert_eq!(tokens[0].pos.idx, 215..216);
+    assert_eq!(tokens[0].pos.ln, 1..2);
+}
+#[test]
+fn line_endings() {
+    use crate::lexer::Lexer;
+    let lf = Lexer::new("a\n  b\n\nc \"x\"").lex().unwrap();
+    for text in ["a\r\n  b\r\n\r\nc \"x\"", "a\r  b\r\rc \"x\""] {
+        let tokens = Lexer::new(text).lex().unwrap();
+        assert_eq!(tokens, lf);
+        for (token, lf) in tokens.iter().zip(&lf) {
+            assert_eq!(token.pos.ln, lf.pos.ln);
+            assert_eq!(token.pos.col, lf.pos.col);
+        }
+    }
+    assert_eq!(lf[2].pos.ln, 3..4);
+    assert_eq!(lf[2].pos.col, 0..1);
+}
+#[test]
+fn line_endings_indent() {
+    use crate::indent_lexer::Lexer;
+    let lf = Lexer::new("a\n  b\n\n\tc").lex().unwrap();
+    for text in ["a\r\n  b\r\n\r\n\tc", "a\r  b\r\r\tc"] {
+        let lines = Lexer::new(text).lex().unwrap();
+        assert_eq!(lines.len(), 4);
+        for (line, lf) in lines.iter().zip(&lf) {
+            assert_eq!(line.ln, lf.ln);
+            assert_eq!(line.indent, lf.indent);
+            assert_eq!(line.tokens, lf.tokens);
+        }
+    }
+}
+#[test]
+fn normalize_newlines() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let tokens = Lexer::new("\"a\r\nb\rc\nd\" x")
+        .normalize_newlines(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::String("a\nb\nc\nd".into()));
+    assert_eq!(tokens[1].pos.ln, 3..4);
+    let tokens = Lexer::new("\"a\r\nb\"").lex().unwrap();
+    assert_eq!(tokens[0].value, Token::String("a\r\nb".into()));
+}
+#[test]
+fn line_endings_stream() {
+    use crate::stream_lexer::Lexer;
+    let tokens = Lexer::new(&b"a\r\nb\rc"[..]).capacity(1).lex().unwrap();
+    assert_eq!(tokens[1].pos.ln, 1..2);
+    assert_eq!(tokens[2].pos.ln, 2..3);
+    assert_eq!(tokens[2].pos.col, 0..1);
+}
+#[test]
+fn bidi_controls() {
+    use crate::error::ErrorType;
+    use crate::lexer::{Lexer, Policy};
+    let text = "x = \"user\u{202e} \u{2066}// admin\u{2069}\"";
+    assert!(Lexer::new(text).symbols(&["="]).lex().is_ok());
+    let error = Lexer::new(text)
+        .symbols(&["="])
+        .bidi_controls(Policy::Deny)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::BidiControl('\u{202e}'));
+    assert_eq!(error.pos.idx, 9..12);
+    assert_eq!(error.pos.col, 9..10);
+    let mut lexer = Lexer::new(text).symbols(&["="]).bidi_controls(Policy::Warn);
+    assert_eq!(lexer.lex().unwrap().len(), 3);
+    assert_eq!(lexer.warnings.len(), 3);
+    assert_eq!(
+        lexer.warnings[1].error_type,
+        ErrorType::BidiControl('\u{2066}')
+    );
+    assert_eq!(lexer.warnings[1].pos.col, 11..12);
+    let error = Lexer::new("a \u{202d}")
+        .bidi_controls(Policy::Deny)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::BidiControl('\u{202d}'));
+    for policy in [Policy::Allow, Policy::Warn] {
+        let error = Lexer::new("a \u{2066} b")
+            .bidi_controls(policy)
+            .lex()
+            .unwrap_err();
+        assert_eq!(error.error_type, ErrorType::BidiControl('\u{2066}'));
+        assert_eq!(error.pos.idx, 2..5);
+    }
+}
+#[test]
+fn confusables() {
+    use crate::error::ErrorType;
+    use crate::lexer::{Lexer, Policy};
+    let error = Lexer::new("ok pаypal")
+        .unicode_idents(true)
+        .confusables(Policy::Deny)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::MixedScript("pаypal".into()));
+    assert_eq!(error.pos.idx, 3..10);
+    let mut lexer = Lexer::new("асе 変数x größe переменная")
+        .unicode_idents(true)
+        .confusables(Policy::Warn);
+    assert_eq!(lexer.lex().unwrap().len(), 4);
+    assert_eq!(lexer.warnings.len(), 1);
+    assert_eq!(
+        lexer.warnings[0].error_type,
+        ErrorType::Confusable("асе".into(), "ace".into())
+    );
+    assert!(Lexer::new("pаypal").unicode_idents(true).lex().is_ok());
+}
+#[test]
+fn confusables_stream() {
+    use crate::lexer::Policy;
+    use crate::stream_lexer::Lexer;
+    let mut lexer = Lexer::new("a\n\"\u{202a}\" ѕх".as_bytes())
+        .capacity(1)
+        .unicode_idents(true)
+        .bidi_controls(Policy::Warn)
+        .confusables(Policy::Warn);
+    assert_eq!(lexer.lex().unwrap().len(), 3);
+    let warnings = &lexer.lexer.warnings;
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].pos.idx, 3..6);
+    assert_eq!(warnings[0].pos.ln, 1..2);
+    assert_eq!(warnings[1].pos.idx, 8..12);
+}
+#[test]
+fn visual_columns() {
+    use crate::lexer::{Lexer, Options};
+    let tokens = Lexer::new("\tx\t\"変数\" y").lex().unwrap();
+    assert_eq!(tokens[0].pos.col, 1..2);
+    assert_eq!(tokens[0].pos.vcol, 4..5);
+    assert_eq!(tokens[1].pos.col, 3..7);
+    assert_eq!(tokens[1].pos.vcol, 8..14);
+    assert_eq!(tokens[2].pos.vcol, 15..16);
+    let tokens = Lexer::new("\tx").tab_width(8).lex().unwrap();
+    assert_eq!(tokens[0].pos.vcol, 8..9);
+    let options = Options {
+        tab_width: 0,
+        ..Default::default()
+    };
+    let tokens = Lexer::with_options("\tx", options).lex().unwrap();
+    assert_eq!(tokens[0].pos.vcol, 1..2);
+}
+#[test]
+fn visual_columns_graphemes() {
+    use crate::lexer::Lexer;
+    let tokens = Lexer::new("\"e\u{301}\" \"👨\u{200d}👩\u{200d}👧\" \"🇩🇪🇫🇷\" x")
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].pos.col, 0..4);
+    assert_eq!(tokens[0].pos.vcol, 0..3);
+    assert_eq!(tokens[1].pos.vcol, 4..8);
+    assert_eq!(tokens[2].pos.vcol, 9..15);
+    assert_eq!(tokens[3].pos.vcol, 16..17);
+}
+#[test]
+fn visual_columns_flags() {
+    use crate::lexer::Lexer;
+    let tokens = Lexer::new("\"🇩🇪🇫\" x").lex().unwrap();
+    assert_eq!(tokens[0].pos.vcol, 0..6);
+    assert_eq!(tokens[1].pos.vcol, 7..8);
+    let text = format!("\"{}\" x", "🇩🇪".repeat(50_000));
+    let tokens = Lexer::new(&text).lex().unwrap();
+    assert_eq!(tokens[1].pos.vcol, 100_003..100_004);
+}
+#[test]
+fn visual_columns_stream() {
+    use crate::lexer;
+    use crate::stream_lexer::Lexer;
+    let text = "\t変数 = \"ｆｕｌｌ\"\n\t\ty";
+    let expected = lexer::Lexer::new(text)
+        .symbols(&["="])
+        .unicode_idents(true)
+        .tab_width(2)
+        .lex()
+        .unwrap();
+    let tokens = Lexer::new(text.as_bytes())
+        .capacity(1)
+        .symbols(&["="])
+        .unicode_idents(true)
+        .tab_width(2)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[3].pos.vcol, 4..5);
+    for (token, expected) in tokens.iter().zip(&expected) {
+        assert_eq!(token.pos, expected.pos);
+    }
+}
+#[test]
+fn radix_prefixes() {
+    use crate::lexer::Lexer;
+    use crate::tokens::{Radix, Token};
+    let text = "0xFF 0o17 0b1010 0XdeadBEEF 10";
+    let tokens = Lexer::new(text).radix_prefixes(true).lex().unwrap();
+    assert_eq!(tokens.len(), 5);
+    assert_eq!(tokens[0].value, Token::Int(255, Radix::Hexadecimal));
+    assert_eq!(tokens[0].pos.idx, 0..4);
+    assert_eq!(tokens[1].value, Token::Int(15, Radix::Octal));
+    assert_eq!(tokens[2].value, Token::Int(10, Radix::Binary));
+    assert_eq!(tokens[3].value, Token::Int(0xdeadbeef, Radix::Hexadecimal));
+    assert_eq!(tokens[4].value, Token::Int(10, Radix::Decimal));
+    let radixes: Vec<_> = tokens
+        .iter()
+        .map(|token| Radix::of(&text[token.pos.idx.clone()]))
+        .collect();
+    assert_eq!(
+        radixes,
+        [
+            Radix::Hexadecimal,
+            Radix::Octal,
+            Radix::Binary,
+            Radix::Hexadecimal,
+            Radix::Decimal
+        ]
+    );
+    let tokens = Lexer::new("0xFF").lex().unwrap();
+    assert_eq!(tokens[0].value, Token::Int(0, Radix::Decimal));
+    assert_eq!(tokens[1].value, Token::Ident("xFF".into()));
+}
+#[test]
+fn radix_prefix_errors() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    use crate::tokens::Radix;
+    let error = Lexer::new("a 0x").radix_prefixes(true).lex().unwrap_err();
+    assert_eq!(
+        error.error_type,
+        ErrorType::MissingDigits(Radix::Hexadecimal)
+    );
+    assert_eq!(error.pos.idx, 2..4);
+    let error = Lexer::new("0b2").radix_prefixes(true).lex().unwrap_err();
+    assert_eq!(
+        error.error_type,
+        ErrorType::InvalidDigit('2', Radix::Binary)
+    );
+    assert_eq!(error.pos.idx, 2..3);
+    let error = Lexer::new("0o178").radix_prefixes(true).lex().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::InvalidDigit('8', Radix::Octal));
+    assert_eq!(error.pos.idx, 4..5);
+}
+#[test]
+fn radix_prefixes_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::tokens::{Radix, Token};
+    let lines = Lexer::new("  0x10 0b11")
+        .radix_prefixes(true)
+        .lex()
+        .unwrap();
+    assert_eq!(lines[0].tokens[0].value, Token::Int(16, Radix::Hexadecimal));
+    assert_eq!(lines[0].tokens[1].value, Token::Int(3, Radix::Binary));
+}
+#[test]
+fn digit_separators() {
+    use crate::lexer::Lexer;
+    use crate::tokens::{Radix, Token};
+    let tokens = Lexer::new("1_000_000 1.234_567 0xFF_FF")
+        .digit_separator('_')
+        .radix_prefixes(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::Int(1_000_000, Radix::Decimal));
+    assert_eq!(tokens[0].pos.idx, 0..9);
+    assert_eq!(tokens[1].value, Token::Float(1.234_567));
+    assert_eq!(tokens[2].value, Token::Int(0xFFFF, Radix::Hexadecimal));
+    let tokens = Lexer::new("1'000'000").digit_separator('\'').lex().unwrap();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].value, Token::Int(1_000_000, Radix::Decimal));
+    let tokens = Lexer::new("1_000").lex().unwrap();
+    assert_eq!(tokens[1].value, Token::Ident("_000".into()));
+}
+#[test]
+fn digit_separator_errors() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    for (text, idx) in [
+        ("1_", 1),
+        ("1__0", 1),
+        ("3._14", 2),
+        ("3.14_", 4),
+        ("0x_1", 2),
+    ] {
+        let error = Lexer::new(text)
+            .digit_separator('_')
+            .radix_prefixes(true)
+            .lex()
+            .unwrap_err();
+        assert_eq!(error.error_type, ErrorType::MisplacedSeparator('_'));
+        assert_eq!(error.pos.idx, idx..idx + 1);
+    }
+}
+#[test]
+fn exponents() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let tokens = Lexer::new("1e10 2.5E-3 6e+2 1_0e1_0")
+        .digit_separator('_')
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens[0].value, Token::Float(1e10));
+    assert_eq!(tokens[1].value, Token::Float(2.5e-3));
+    assert_eq!(tokens[1].pos.idx, 5..11);
+    assert_eq!(tokens[2].value, Token::Float(600.0));
+    assert_eq!(tokens[3].value, Token::Float(1e11));
+    let tokens = Lexer::new("1e10").exponents(false).lex().unwrap();
+    assert_eq!(tokens[1].value, Token::Ident("e10".into()));
+}
+#[test]
+fn exponents_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::tokens::Token;
+    let lines = Lexer::new("x\n  3E2").lex().unwrap();
+    assert_eq!(lines[1].tokens[0].value, Token::Float(300.0));
+}
+#[test]
+fn malformed_exponent() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    for (text, idx) in [("1e", 0..2), ("x 1e+", 2..5), ("1.5E-a", 0..5)] {
+        let error = Lexer::new(text).lex().unwrap_err();
+        assert_eq!(error.error_type, ErrorType::MalformedExponent);
+        assert_eq!(error.pos.idx, idx);
+    }
+}
+#[test]
+fn int_overflow() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    use crate::tokens::{Radix, Token};
+    let tokens = Lexer::new("9223372036854775807").lex().unwrap();
+    assert_eq!(tokens[0].value, Token::Int(i64::MAX, Radix::Decimal));
+    let error = Lexer::new("x 99999999999999999999").lex().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::IntOverflow);
+    assert_eq!(error.pos.idx, 2..22);
+    let error = Lexer::new("0x1_0000_0000_0000_0000")
+        .radix_prefixes(true)
+        .digit_separator('_')
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::IntOverflow);
+    assert_eq!(error.pos.idx, 0..23);
+}
+#[test]
+fn int_widths() {
+    use crate::error::ErrorType;
+    use crate::lexer::{IntWidth, Lexer};
+    use crate::tokens::{Radix, Token};
+    let tokens = Lexer::new("1 18446744073709551615")
+        .int_width(IntWidth::U64)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::Int(1, Radix::Decimal));
+    assert_eq!(
+        tokens[1].value,
+        Token::WideInt(u64::MAX as i128, Radix::Decimal)
+    );
+    let error = Lexer::new("18446744073709551616")
+        .int_width(IntWidth::U64)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::IntOverflow);
+    let tokens = Lexer::new("170141183460469231731687303715884105727")
+        .int_width(IntWidth::I128)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::WideInt(i128::MAX, Radix::Decimal));
+    let tokens =
+        Lexer::new("123_456_789_012_345_678_901_234_567_890_123_456 0xFFFF_FFFF_FFFF_FFFF")
+            .int_width(IntWidth::Big)
+            .digit_separator('_')
+            .radix_prefixes(true)
+            .lex()
+            .unwrap();
+    assert_eq!(
+        tokens[0].value,
+        Token::BigInt("123456789012345678901234567890123456".into())
+    );
+    assert_eq!(tokens[1].value, Token::BigInt("0xFFFFFFFFFFFFFFFF".into()));
+}
+#[test]
+fn suffixes() {
+    use crate::lexer::Lexer;
+    use crate::tokens::{Radix, Token};
+    let tokens = Lexer::new("10u8 255i64 1.0f32 10ms 5px 2em 0xFFu8 3 x")
+        .suffixes(&["u8", "i64", "f32", "ms", "px", "em"])
+        .radix_prefixes(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 9);
+    assert_eq!(
+        tokens[0].value,
+        Token::Suffixed(Box::new(Token::Int(10, Radix::Decimal)), "u8".into())
+    );
+    assert_eq!(tokens[0].pos.idx, 0..4);
+    assert_eq!(
+        tokens[2].value,
+        Token::Suffixed(Box::new(Token::Float(1.0)), "f32".into())
+    );
+    assert_eq!(
+        tokens[5].value,
+        Token::Suffixed(Box::new(Token::Int(2, Radix::Decimal)), "em".into())
+    );
+    assert_eq!(
+        tokens[6].value,
+        Token::Suffixed(Box::new(Token::Int(255, Radix::Hexadecimal)), "u8".into())
+    );
+    assert_eq!(tokens[7].value, Token::Int(3, Radix::Decimal));
+    let tokens = Lexer::new("10kg").suffixes(&["ms"]).lex().unwrap();
+    assert_eq!(tokens[1].value, Token::Ident("kg".into()));
+    let tokens = Lexer::new("3 1e5 10ms")
+        .suffixes(&["", "ms"])
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::Int(3, Radix::Decimal));
+    assert_eq!(tokens[1].value, Token::Float(1e5));
+    assert_eq!(
+        tokens[2].value,
+        Token::Suffixed(Box::new(Token::Int(10, Radix::Decimal)), "ms".into())
+    );
+    let mut lexer = Lexer::new("3 1e5");
+    lexer.options.suffixes = vec![String::new()];
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens[0].value, Token::Int(3, Radix::Decimal));
+    assert_eq!(tokens[1].value, Token::Float(1e5));
+}
+#[test]
+fn strict_suffixes() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    let error = Lexer::new("10ms 10kg")
+        .suffixes(&["ms"])
+        .strict_suffixes(true)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnknownSuffix("kg".into()));
+    assert_eq!(error.pos.idx, 7..9);
+}
+#[test]
+fn decimal_point() {
+    use crate::lexer::{DecimalPoint, Lexer};
+    use crate::tokens::{Radix, Token};
+    let tokens = Lexer::new("1..10 1.abs() 1.5")
+        .symbols(&[".", "..", "(", ")"])
+        .decimal_point(DecimalPoint::BeforeDigit)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 9);
+    assert_eq!(tokens[0].value, Token::Int(1, Radix::Decimal));
+    assert_eq!(tokens[1].value, Token::LongSymbol("..".into()));
+    assert_eq!(tokens[2].value, Token::Int(10, Radix::Decimal));
+    assert_eq!(tokens[3].value, Token::Int(1, Radix::Decimal));
+    assert_eq!(tokens[4].value, Token::Symbol('.'));
+    assert_eq!(tokens[5].value, Token::Ident("abs".into()));
+    assert_eq!(tokens[8].value, Token::Float(1.5));
+    let tokens = Lexer::new("1..10").symbols(&[".", ".."]).lex().unwrap();
+    assert_eq!(tokens[0].value, Token::Float(1.0));
+    assert_eq!(tokens[1].value, Token::Symbol('.'));
+}
+#[test]
+fn leading_dot_floats() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let tokens = Lexer::new(".5 .25e2 x.y")
+        .symbols(&["."])
+        .leading_dot_floats(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 5);
+    assert_eq!(tokens[0].value, Token::Float(0.5));
+    assert_eq!(tokens[0].pos.idx, 0..2);
+    assert_eq!(tokens[1].value, Token::Float(25.0));
+    assert_eq!(tokens[3].value, Token::Symbol('.'));
+}
+#[test]
+fn signed_numbers() {
+    use crate::lexer::{IntWidth, Lexer};
+    use crate::tokens::{Radix, Token};
+    let tokens = Lexer::new("[-42, +3.5] x-1 (1)-2 - 3")
+        .symbols(&["[", "]", ",", "(", ")", "-"])
+        .signed_numbers(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 15);
+    assert_eq!(tokens[1].value, Token::Int(-42, Radix::Decimal));
+    assert_eq!(tokens[1].pos.idx, 1..4);
+    assert_eq!(tokens[3].value, Token::Float(3.5));
+    assert_eq!(tokens[6].value, Token::Symbol('-'));
+    assert_eq!(tokens[7].value, Token::Int(1, Radix::Decimal));
+    assert_eq!(tokens[11].value, Token::Symbol('-'));
+    assert_eq!(tokens[12].value, Token::Int(2, Radix::Decimal));
+    assert_eq!(tokens[13].value, Token::Symbol('-'));
+    assert_eq!(tokens[14].value, Token::Int(3, Radix::Decimal));
+    let tokens = Lexer::new("-9223372036854775808, -9223372036854775809")
+        .symbols(&[","])
+        .signed_numbers(true)
+        .int_width(IntWidth::I128)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::Int(i64::MIN, Radix::Decimal));
+    assert_eq!(
+        tokens[2].value,
+        Token::WideInt(i64::MIN as i128 - 1, Radix::Decimal)
+    );
+    let tokens = Lexer::new("-123456789012345678901234567890")
+        .signed_numbers(true)
+        .int_width(IntWidth::Big)
+        .lex()
+        .unwrap();
+    assert_eq!(
+        tokens[0].value,
+        Token::BigInt("-123456789012345678901234567890".into())
+    );
+}
+#[test]
+fn special_floats() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let tokens = Lexer::new("inf, -inf, nan, info")
+        .symbols(&[","])
+        .signed_numbers(true)
+        .special_floats(&[("inf", f64::INFINITY), ("nan", f64::NAN)])
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 7);
+    assert_eq!(tokens[0].value, Token::Float(f64::INFINITY));
+    assert_eq!(tokens[2].value, Token::Float(f64::NEG_INFINITY));
+    assert_eq!(tokens[2].pos.idx, 5..9);
+    assert!(matches!(tokens[4].value, Token::Float(nan) if nan.is_nan()));
+    assert_eq!(tokens[6].value, Token::Ident("info".into()));
+}
+#[test]
+fn escapes() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let tokens = Lexer::new(r#""\0\x41\u{1F600}\u00e9\uD83D\uDE00" '\x7a'"#)
+        .null_escapes(true)
+        .hex_escapes(true)
+        .unicode_escapes(true)
+        .short_unicode_escapes(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::String("\0A😀é😀".into()));
+    assert_eq!(tokens[1].value, Token::Char('z'));
+    let error = Lexer::new(r#""\0""#).lex().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnknownEscape('0'));
+    assert_eq!(error.pos.idx, 1..3);
+}
+#[test]
+fn escape_errors() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    let lex = |text| {
+        Lexer::new(text)
+            .hex_escapes(true)
+            .unicode_escapes(true)
+            .short_unicode_escapes(true)
+            .lex()
+            .unwrap_err()
+    };
+    let error = lex(r#""abc \u{D800}""#);
+    assert_eq!(error.error_type, ErrorType::InvalidCodePoint(0xD800));
+    assert_eq!(error.pos.idx, 5..13);
+    let error = lex(r#""\u{110000}""#);
+    assert_eq!(error.error_type, ErrorType::InvalidCodePoint(0x110000));
+    let error = lex(r#""\uDC00""#);
+    assert_eq!(error.error_type, ErrorType::InvalidCodePoint(0xDC00));
+    assert_eq!(error.pos.idx, 1..7);
+    let error = lex(r#""ab\x4""#);
+    assert_eq!(error.error_type, ErrorType::MalformedEscape("\\x4".into()));
+    assert_eq!(error.pos.idx, 3..6);
+    let error = lex(r#""\u{1F600""#);
+    assert_eq!(
+        error.error_type,
+        ErrorType::MalformedEscape("\\u{1F600".into())
+    );
+    let error = lex(r#""\u12""#);
+    assert_eq!(error.error_type, ErrorType::MalformedEscape("\\u12".into()));
+}
+#[test]
+fn raw_strings() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let text = r###"r"C:\dir" r#"contains "quotes""# r##"a "# b"## raw"###;
+    let tokens = Lexer::new(text).raw_strings(true).lex().unwrap();
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens[0].value, Token::String(r"C:\dir".into()));
+    assert_eq!(tokens[0].pos.idx, 0..9);
+    assert_eq!(
+        tokens[1].value,
+        Token::String(r#"contains "quotes""#.into())
+    );
+    assert_eq!(tokens[2].value, Token::String(r##"a "# b"##.into()));
+    assert_eq!(tokens[3].value, Token::Ident("raw".into()));
+    let error = Lexer::new(r###"r##"never closed"#"###)
+        .raw_strings(true)
+        .lex()
+        .unwrap_err();
+    assert_eq!(
+        error.error_type,
+        ErrorType::UnclosedRawString("\"##".into())
+    );
+    assert_eq!(error.pos.idx, 0..4);
+}
+#[test]
+fn raw_strings_stream() {
+    use crate::stream_lexer::Lexer;
+    use crate::tokens::Token;
+    let string = "\\".repeat(1000);
+    let text = format!("x r#\"{string}\"# y");
+    let tokens: Vec<_> = Lexer::new(text.as_bytes())
+        .capacity(16)
+        .raw_strings(true)
+        .map(|token| token.unwrap().value)
+        .collect();
+    assert_eq!(tokens[1], Token::String(string.into()));
+    assert_eq!(tokens.len(), 3);
+}
+#[test]
+fn unclosed_string_indent() {
+    use crate::error::ErrorType;
+    use crate::indent_lexer::Lexer;
+    let error = Lexer::new("x = \"one\ntwo\"")
+        .symbols(&["="])
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnclosedString);
+    assert_eq!(error.pos.ln, 0..1);
+}
+#[test]
+fn unclosed_literals_indent() {
+    use crate::error::ErrorType;
+    use crate::indent_lexer::Lexer;
+    use crate::lexer::Quote;
+    let error = Lexer::new("x b\"x\ny\"")
+        .byte_literals(true)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnclosedString);
+    assert_eq!(error.pos.idx, 2..4);
+    let error = Lexer::new("x `x\ny`")
+        .quote('`', Quote::Ident)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnclosedString);
+    assert_eq!(error.pos.idx, 2..3);
+    let error = Lexer::new("x r\"x\ny\"")
+        .raw_strings(true)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnclosedRawString("\"".into()));
+    assert_eq!(error.pos.idx, 2..4);
+    let lines = Lexer::new("x r\"x\ny\"")
+        .raw_strings(true)
+        .multiline_strings(true)
+        .lex()
+        .unwrap();
+    assert_eq!(lines.len(), 1);
+}
+#[test]
+fn multiline_strings_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::tokens::Token;
+    let text = "x = \"one\n  two\" y\n  z";
+    let lines = Lexer::new(text)
+        .symbols(&["="])
+        .multiline_strings(true)
+        .lex()
+        .unwrap();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].len(), 4);
+    assert_eq!(lines[0].tokens[2].value, Token::String("one\n  two".into()));
+    assert_eq!(lines[0].tokens[3].pos.ln, 1..2);
+    assert_eq!(lines[1].ln, 2);
+    assert_eq!(lines[1].indent, 2);
+}
+#[test]
+fn single_line_strings() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let error = Lexer::new("x = \"one\ntwo\"")
+        .symbols(&["="])
+        .multiline_strings(false)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnclosedString);
+    assert_eq!(error.pos.idx, 4..5);
+    let tokens = Lexer::new("\"\"\"one\ntwo\"\"\"")
+        .triple_quoted_strings(true)
+        .multiline_strings(false)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::String("one\ntwo".into()));
+}
+#[test]
+fn dedent_mixed_whitespace() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let text = "\"\"\"\n\ta\n    b\n\"\"\" \"\"\"\n\t  a\n\t    b\n\t \tc\n\"\"\"";
+    let tokens = Lexer::new(text)
+        .triple_quoted_strings(true)
+        .dedent(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::String("\ta\n    b\n".into()));
+    assert_eq!(tokens[1].value, Token::String(" a\n   b\n\tc\n".into()));
+}
+#[test]
+fn triple_quoted_strings_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::tokens::Token;
+    let text = "def f():\n    \"\"\"\n    Says \"hi\".\n\n      Indented.\n    \"\"\"\n    pass";
+    let lines = Lexer::new(text)
+        .symbols(&["(", ")", ":"])
+        .triple_quoted_strings(true)
+        .dedent(true)
+        .lex()
+        .unwrap();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[1].ln, 1);
+    assert_eq!(lines[1].indent, 4);
+    assert_eq!(
+        lines[1].tokens[0].value,
+        Token::String("Says \"hi\".\n\n  Indented.\n".into())
+    );
+    assert_eq!(lines[1].tokens[0].pos.ln, 1..6);
+    assert_eq!(lines[2].ln, 6);
+    assert_eq!(lines[2].indent, 4);
+    let lines = Lexer::new("\"\"\"a\n  b\"\"\" \"\"")
+        .triple_quoted_strings(true)
+        .lex()
+        .unwrap();
+    assert_eq!(lines[0].tokens[0].value, Token::String("a\n  b".into()));
+    assert_eq!(lines[0].tokens[1].value, Token::String("".into()));
+}
+#[test]
+fn interpolation() {
+    use crate::lexer::Lexer;
+    use crate::tokens::{Segment, Token};
+    let text = r#"x = "hello ${user.name}! ${ {"a": "}"}["a"] } \${no}""#;
+    let tokens = Lexer::new(text)
+        .symbols(&["=", ".", "!", "{", "}", ":", "[", "]"])
+        .interpolation("${", "}")
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[2].pos.idx, 4..text.len());
+    let Token::Template(segments) = &tokens[2].value else {
+        panic!("expected a template, got {:?}", tokens[2].value);
+    };
+    assert_eq!(segments.len(), 5);
+    assert_eq!(segments[0], Segment::Literal("hello ".into()));
+    let Segment::Expr(expr) = &segments[1] else {
+        panic!("expected an expression");
+    };
+    assert_eq!(expr.len(), 3);
+    assert_eq!(expr[0].value, Token::Ident("user".into()));
+    assert_eq!(expr[2].value, Token::Ident("name".into()));
+    assert_eq!(&text[expr[2].pos.idx.clone()], "name");
+    assert_eq!(segments[2], Segment::Literal("! ".into()));
+    let Segment::Expr(expr) = &segments[3] else {
+        panic!("expected an expression");
+    };
+    assert_eq!(expr.len(), 8);
+    assert_eq!(expr[3].value, Token::String("}".into()));
+    assert_eq!(segments[4], Segment::Literal(" ${no}".into()));
+    let tokens = Lexer::new("\"plain\"")
+        .interpolation("${", "}")
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::String("plain".into()));
+}
+#[test]
+fn interpolation_errors() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    let error = Lexer::new("\"a {b\"")
+        .interpolation("{", "}")
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnclosedString);
+    let error = Lexer::new("\"a {b")
+        .interpolation("{", "}")
+        .lex()
+        .unwrap_err();
+    assert_eq!(
+        error.error_type,
+        ErrorType::UnclosedInterpolation("}".into())
+    );
+    assert_eq!(error.pos.idx, 3..4);
+}
+#[test]
+fn raw_strings_quotes() {
+    use crate::lexer::{Lexer, Quote};
+    use crate::tokens::Token;
+    let tokens = Lexer::new("r\"x\" r'y' r#'a'b'#")
+        .raw_strings(true)
+        .quote('"', Quote::Ident)
+        .quote('\'', Quote::String)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens[0].value, Token::Ident("r".into()));
+    assert_eq!(tokens[1].value, Token::Ident("x".into()));
+    assert_eq!(tokens[2].value, Token::String("y".into()));
+    assert_eq!(tokens[3].value, Token::String("a'b".into()));
+    assert_eq!(tokens[3].pos.idx, 10..18);
+}
+#[test]
+fn interpolation_empty_delimiters() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    for (opener, closer) in [("", "}"), ("${", ""), ("", "")] {
+        let tokens = Lexer::new("\"a ${b} c\"")
+            .interpolation(opener, closer)
+            .lex()
+            .unwrap();
+        assert_eq!(tokens[0].value, Token::String("a ${b} c".into()));
+    }
+    let mut lexer = Lexer::new("\"a\"");
+    lexer.options.interpolation = Some((String::new(), String::new()));
+    let tokens = lexer.lex().unwrap();
+    assert_eq!(tokens[0].value, Token::String("a".into()));
+}
+#[test]
+fn quotes() {
+    use crate::lexer::{Lexer, Quote};
+    use crate::tokens::Token;
+    let text = "'hello' \"my column\" `cmd` 'it''s'";
+    let tokens = Lexer::new(text)
+        .quote('\'', Quote::String)
+        .quote('"', Quote::Ident)
+        .quote('`', Quote::String)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 5);
+    assert_eq!(tokens[0].value, Token::String("hello".into()));
+    assert_eq!(tokens[1].value, Token::Ident("my column".into()));
+    assert_eq!(tokens[1].pos.idx, 8..19);
+    assert_eq!(tokens[2].value, Token::String("cmd".into()));
+    assert_eq!(tokens[3].value, Token::String("it".into()));
+    assert_eq!(tokens[4].value, Token::String("s".into()));
+    let tokens = Lexer::new("'''a'b''' 'a\\'b'")
+        .quote('\'', Quote::String)
+        .triple_quoted_strings(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::String("a'b".into()));
+    assert_eq!(tokens[1].value, Token::String("a'b".into()));
+    let tokens = Lexer::new("'a")
+        .symbols(&["'"])
+        .quote('\'', Quote::Disabled)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::Symbol('\''));
+    assert_eq!(tokens[1].value, Token::Ident("a".into()));
+}
+#[test]
+fn quotes_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::lexer::Quote;
+    use crate::tokens::Token;
+    let lines = Lexer::new("  echo 'hi'")
+        .quote('\'', Quote::String)
+        .lex()
+        .unwrap();
+    assert_eq!(lines[0].indent, 2);
+    assert_eq!(lines[0].tokens[1].value, Token::String("hi".into()));
+}
+#[test]
+fn byte_literals() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let tokens = Lexer::new(r#"b"\x00\xff\n\"" b'a' b'\'' b"#)
+        .byte_literals(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(
+        tokens[0].value,
+        Token::ByteString(vec![0, 255, b'\n', b'"'])
+    );
+    assert_eq!(tokens[0].pos.idx, 0..15);
+    assert_eq!(tokens[1].value, Token::Byte(b'a'));
+    assert_eq!(tokens[1].pos.idx, 16..20);
+    assert_eq!(tokens[2].value, Token::Byte(b'\''));
+    assert_eq!(tokens[3].value, Token::Ident("b".into()));
+}
+#[test]
+fn byte_literal_errors() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    let lex = |text| Lexer::new(text).byte_literals(true).lex().unwrap_err();
+    let error = lex("b\"abé\"");
+    assert_eq!(error.error_type, ErrorType::NonAsciiByte('é'));
+    assert_eq!(error.pos.idx, 4..6);
+    let error = lex("b'λ'");
+    assert_eq!(error.error_type, ErrorType::NonAsciiByte('λ'));
+    let error = lex(r#"b"a\u{41}""#);
+    assert_eq!(error.error_type, ErrorType::InvalidByteEscape("\\u".into()));
+    assert_eq!(error.pos.idx, 3..5);
+    let error = lex(r#"b"\xf""#);
+    assert_eq!(error.error_type, ErrorType::MalformedEscape("\\xf".into()));
+}
+#[test]
+fn heredocs() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let text = "cat <<EOF | sort; x\nb\na\nEOF\ny <<-END\n  one\n  END\nz";
+    let tokens = Lexer::new(text)
+        .symbols(&["|", ";", "<<"])
+        .heredocs("<<")
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 9);
+    assert_eq!(tokens[1].value, Token::String("b\na\n".into()));
+    assert_eq!(tokens[1].pos.idx, 4..9);
+    assert_eq!(tokens[2].value, Token::Symbol('|'));
+    assert_eq!(tokens[5].value, Token::Ident("x".into()));
+    assert_eq!(tokens[5].pos.ln, 0..1);
+    assert_eq!(tokens[6].value, Token::Ident("y".into()));
+    assert_eq!(tokens[6].pos.ln, 4..5);
+    assert_eq!(tokens[7].value, Token::String("  one\n".into()));
+    assert_eq!(tokens[8].value, Token::Ident("z".into()));
+    assert_eq!(tokens[8].pos.ln, 7..8);
+    let tokens = Lexer::new("a << 2 <<A <<B\n1\nA\n2\nB")
+        .symbols(&["<<"])
+        .heredocs("<<")
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 5);
+    assert_eq!(tokens[1].value, Token::LongSymbol("<<".into()));
+    assert_eq!(tokens[3].value, Token::String("1\n".into()));
+    assert_eq!(tokens[4].value, Token::String("2\n".into()));
+}
+#[test]
+fn heredoc_errors() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    let error = Lexer::new("cat <<-EOF\nbody\nEOFX\n")
+        .heredocs("<<")
+        .lex()
+        .unwrap_err();
+    assert_eq!(
+        error.error_type,
+        ErrorType::UnterminatedHeredoc("EOF".into())
+    );
+    assert_eq!(error.pos.idx, 4..10);
+    let error = Lexer::new("x <<EOF \"a\nEOF\nb\"\n")
+        .heredocs("<<")
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::HeredocInLiteral);
+    assert_eq!(error.pos.idx, 8..17);
+    let error = Lexer::new("x <<EOF \"a\r\nEOF\r\nb\"\r\n")
+        .heredocs("<<")
+        .normalize_newlines(true)
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::HeredocInLiteral);
+}
+#[test]
+fn heredocs_indent() {
+    use crate::indent_lexer::Lexer;
+    use crate::tokens::Token;
+    let lines = Lexer::new("run <<EOF\n    body\nEOF\n  next")
+        .heredocs("<<")
+        .lex()
+        .unwrap();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].tokens[1].value, Token::String("    body\n".into()));
+    assert_eq!(lines[1].ln, 3);
+    assert_eq!(lines[1].indent, 2);
+}
+#[test]
+fn char_errors() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    let lex = |text| Lexer::new(text).byte_literals(true).lex().unwrap_err();
+    for text in ["'", "'\\", "'a", "x 'ab\n'"] {
+        assert_eq!(
+            lex(text).error_type,
+            ErrorType::UnterminatedChar,
+            "{text:?}"
+        );
+    }
+    let error = lex("x ''");
+    assert_eq!(error.error_type, ErrorType::EmptyChar);
+    assert_eq!(error.pos.idx, 2..4);
+    let error = lex("'ab' x");
+    assert_eq!(error.error_type, ErrorType::TooManyChars);
+    assert_eq!(error.pos.idx, 0..4);
+    assert_eq!(lex("b''").error_type, ErrorType::EmptyChar);
+    assert_eq!(lex("b'ab'").error_type, ErrorType::TooManyChars);
+    assert_eq!(lex("b'").error_type, ErrorType::UnterminatedChar);
+}
+#[test]
+fn lifetimes() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let tokens = Lexer::new("&'a str 'b' 'outer: 'c")
+        .symbols(&["&", ":"])
+        .lifetimes(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens.len(), 7);
+    assert_eq!(tokens[1].value, Token::Lifetime("a".into()));
+    assert_eq!(tokens[1].pos.idx, 1..3);
+    assert_eq!(tokens[3].value, Token::Char('b'));
+    assert_eq!(tokens[4].value, Token::Lifetime("outer".into()));
+    assert_eq!(tokens[6].value, Token::Lifetime("c".into()));
+    let error = Lexer::new("'abc'").lifetimes(true).lex().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::TooManyChars);
+}
+#[test]
+fn escape_tables() {
+    use crate::lexer::Lexer;
+    use crate::tokens::Token;
+    let tokens = Lexer::new(r#""\e[0m\n\t" '\a'"#)
+        .escapes(&[('e', '\x1b'), ('n', '\n'), ('a', '\x07')])
+        .unknown_escapes(crate::lexer::Policy::Allow)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::String("\x1b[0m\n\\t".into()));
+    assert_eq!(tokens[1].value, Token::Char('\x07'));
+    // octal escapes like C's `\101`
+    let tokens = Lexer::new(r#""\101\102\n""#)
+        .escape_decoder(|rest| {
+            let digits = rest.get(..3)?;
+            let code = u32::from_str_radix(digits, 8).ok()?;
+            Some((char::from_u32(code)?, 3))
+        })
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::String("AB\n".into()));
+    let tokens = Lexer::new(r#"b"\e\n""#)
+        .escapes(&[('e', '\x1b'), ('n', '\n')])
+        .byte_literals(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::ByteString(vec![0x1b, b'\n']));
+}
+#[test]
+fn unknown_escapes() {
+    use crate::error::ErrorType;
+    use crate::lexer::{Lexer, Policy};
+    use crate::tokens::Token;
+    let error = Lexer::new(r#""a\d""#).lex().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::UnknownEscape('d'));
+    assert_eq!(error.pos.idx, 2..4);
+    let mut lexer = Lexer::new(r#""\d+\.\"" '\w'"#).unknown_escapes(Policy::Warn);
+    let error = lexer.lex().unwrap_err();
+    assert_eq!(error.error_type, ErrorType::TooManyChars);
+    assert_eq!(lexer.warnings.len(), 3);
+    assert_eq!(lexer.warnings[1].error_type, ErrorType::UnknownEscape('.'));
+    assert_eq!(lexer.warnings[1].pos.idx, 4..6);
+    let tokens = Lexer::new(r#""\d+\.\"" b"\w""#)
+        .unknown_escapes(Policy::Allow)
+        .byte_literals(true)
+        .lex()
+        .unwrap();
+    assert_eq!(tokens[0].value, Token::String(r#"\d+\.""#.into()));
+    assert_eq!(tokens[1].value, Token::ByteString(b"\\w".to_vec()));
+}
+#[test]
+fn escape_decoder_errors() {
+    use crate::error::ErrorType;
+    use crate::lexer::Lexer;
+    let error = Lexer::new(r#""\ab""#)
+        .escape_decoder(|_| Some(('X', 100)))
+        .lex()
+        .unwrap_err();
+    assert_eq!(
+        error.error_type,
+        ErrorType::MalformedEscape("\\ab\"".into())
+    );
+    assert_eq!(error.pos.idx, 1..2);
+    let error = Lexer::new("\"\\éa\"")
+        .escape_decoder(|_| Some(('X', 1)))
+        .lex()
+        .unwrap_err();
+    assert_eq!(
+        error.error_type,
+        ErrorType::MalformedEscape("\\éa\"".into())
+    );
+    let error = Lexer::new("\"\\\"")
+        .escape_decoder(|_| Some(('X', 0)))
+        .lex()
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::MalformedEscape("\\".into()));
+    assert_eq!(error.pos.idx, 1..2);
+}
+#[test]
+fn stream_huge_token() {
+    use crate::stream_lexer::Lexer;
+    use crate::tokens::Token;
+    use std::io::Read;
+    // hands out at most 7 bytes per read, like a slow pipe
+    struct Trickle<'a>(&'a [u8]);
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let len = buf.len().min(7);
+            self.0.read(&mut buf[..len])
+        }
+    }
+    let string = "a".repeat(1 << 20);
+    let text = format!("x \"{string}\" y");
+    let tokens: Vec<_> = Lexer::new(Trickle(text.as_bytes()))
+        .capacity(16)
+        .map(|token| token.unwrap())
+        .collect();
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[1].value, Token::String(string.into()));
+    assert_eq!(tokens[2].pos.idx, text.len() - 1..text.len());
+}
+#[test]
+fn radix_prefixes_stream() {
+    use crate::lexer::IntWidth;
+    use crate::stream_lexer::Lexer;
+    use crate::tokens::{Radix, Token};
+    let tokens: Vec<_> = Lexer::new("0o17 0xFFFFFFFFFFFFFFFF 0b1u8 7".as_bytes())
+        .radix_prefixes(true)
+        .int_width(IntWidth::U64)
+        .suffixes(&["u8"])
+        .map(|token| token.unwrap().value)
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Int(15, Radix::Octal),
+            Token::WideInt(u64::MAX as i128, Radix::Hexadecimal),
+            Token::Suffixed(Box::new(Token::Int(1, Radix::Binary)), "u8".into()),
+            Token::Int(7, Radix::Decimal),
+        ]
+    );
 }
@@ -1,25 +1,134 @@
+use std::{borrow::Cow, fmt::Display};
+
+use crate::error::Located;
+
 #[derive(Debug, Clone, PartialEq)]
-pub enum Token {
-    Ident(String),
-    Keyword(String),
-    Int(i64),
+pub enum Token<'src> {
+    Ident(Cow<'src, str>),
+    Keyword(Cow<'src, str>),
+    /// An integer literal and the radix it was written in.
+    Int(i64, Radix),
+    /// An integer literal that does not fit into an `i64`, and the radix it was written in.
+    WideInt(i128, Radix),
+    /// An integer literal of any size, without digit separators but with its radix prefix.
+    BigInt(Cow<'src, str>),
     Float(f64),
     Char(char),
-    String(String),
+    String(Cow<'src, str>),
+    Byte(u8),
+    ByteString(Vec<u8>),
     Symbol(char),
-    LongSymbol(String),
+    LongSymbol(Cow<'src, str>),
+    Shebang(Cow<'src, str>),
+    /// A lifetime or label like `'a`, without its quote.
+    Lifetime(Cow<'src, str>),
+    /// A numeric token directly followed by one of the registered suffixes, like `10u8`.
+    Suffixed(Box<Token<'src>>, Cow<'src, str>),
+    /// A string with interpolated expressions, like `"hello ${name}!"`.
+    Template(Vec<Segment<'src>>),
 }
-impl Token {
+impl Token<'_> {
     pub fn name(&self) -> String {
         match self {
             Token::Ident(_) => "identifier".to_string(),
             Token::Keyword(kw) => kw.to_string(),
-            Token::Int(_) => "integer".to_string(),
+            Token::Int(..) | Token::WideInt(..) | Token::BigInt(_) => "integer".to_string(),
             Token::Float(_) => "decimal point number".to_string(),
             Token::Char(_) => "character".to_string(),
             Token::String(_) => "string".to_string(),
+            Token::Byte(_) => "byte".to_string(),
+            Token::ByteString(_) => "byte string".to_string(),
             Token::Symbol(sym) => format!("{sym:?}"),
             Token::LongSymbol(sym) => format!("{sym:?}"),
+            Token::Shebang(_) => "shebang".to_string(),
+            Token::Lifetime(_) => "lifetime".to_string(),
+            Token::Suffixed(number, _) => number.name(),
+            Token::Template(_) => "template string".to_string(),
+        }
+    }
+    /// Detaches the token from the source text it borrows from.
+    pub fn into_owned(self) -> Token<'static> {
+        match self {
+            Token::Ident(ident) => Token::Ident(Cow::Owned(ident.into_owned())),
+            Token::Keyword(kw) => Token::Keyword(Cow::Owned(kw.into_owned())),
+            Token::Int(int, radix) => Token::Int(int, radix),
+            Token::WideInt(int, radix) => Token::WideInt(int, radix),
+            Token::BigInt(int) => Token::BigInt(Cow::Owned(int.into_owned())),
+            Token::Float(float) => Token::Float(float),
+            Token::Char(c) => Token::Char(c),
+            Token::String(string) => Token::String(Cow::Owned(string.into_owned())),
+            Token::Byte(byte) => Token::Byte(byte),
+            Token::ByteString(bytes) => Token::ByteString(bytes),
+            Token::Symbol(sym) => Token::Symbol(sym),
+            Token::LongSymbol(sym) => Token::LongSymbol(Cow::Owned(sym.into_owned())),
+            Token::Shebang(line) => Token::Shebang(Cow::Owned(line.into_owned())),
+            Token::Lifetime(name) => Token::Lifetime(Cow::Owned(name.into_owned())),
+            Token::Suffixed(number, suffix) => Token::Suffixed(
+                Box::new(number.into_owned()),
+                Cow::Owned(suffix.into_owned()),
+            ),
+            Token::Template(segments) => {
+                Token::Template(segments.into_iter().map(Segment::into_owned).collect())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment<'src> {
+    Literal(Cow<'src, str>),
+    /// The tokens between an interpolation's opener and closer.
+    Expr(Vec<Located<Token<'src>>>),
+}
+impl Segment<'_> {
+    pub fn into_owned(self) -> Segment<'static> {
+        match self {
+            Segment::Literal(literal) => Segment::Literal(Cow::Owned(literal.into_owned())),
+            Segment::Expr(tokens) => Segment::Expr(
+                tokens
+                    .into_iter()
+                    .map(|token| Located::new(token.value.into_owned(), token.pos))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Radix {
+    Binary = 2,
+    Octal = 8,
+    Decimal = 10,
+    Hexadecimal = 16,
+}
+impl Radix {
+    /// Recovers the radix of an integer literal from its source text, e.g. the text of a
+    /// [`Token::BigInt`].
+    pub fn of(literal: &str) -> Self {
+        let literal = literal.trim_start_matches(['+', '-']);
+        match literal.get(..2) {
+            Some("0x" | "0X") => Radix::Hexadecimal,
+            Some("0o" | "0O") => Radix::Octal,
+            Some("0b" | "0B") => Radix::Binary,
+            _ => Radix::Decimal,
+        }
+    }
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Radix::Binary => "0b",
+            Radix::Octal => "0o",
+            Radix::Decimal => "",
+            Radix::Hexadecimal => "0x",
+        }
+    }
+}
+impl Display for Radix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Radix::Binary => write!(f, "binary"),
+            Radix::Octal => write!(f, "octal"),
+            Radix::Decimal => write!(f, "decimal"),
+            Radix::Hexadecimal => write!(f, "hexadecimal"),
         }
     }
 }